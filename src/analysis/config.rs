@@ -0,0 +1,143 @@
+use crate::analysis::filter::{LowercaseFilter, PunctuationFilter, StopwordFilter};
+use crate::analysis::japanese::{KanaFoldingFilter, LongVowelFilter, RomajiFilter, WidthFilter};
+use crate::analysis::language::Language;
+use crate::analysis::stem::StemFilter;
+use crate::analysis::tokenizer::{CjkBigramTokenizer, WhitespaceTokenizer, WordTokenizer};
+use crate::analysis::Analyzer;
+use serde::{Deserialize, Serialize};
+
+/// Serializable description of an analyzer. Indexes store this in their metadata, so queries can
+/// rebuild the exact analyzer the index has been built with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyzerConfig {
+    pub tokenizer: TokenizerConfig,
+    pub filters: Vec<FilterConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TokenizerConfig {
+    Whitespace,
+    Word { allowed: Vec<char> },
+    CjkBigram { allowed: Vec<char> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FilterConfig {
+    Lowercase,
+    Punctuation,
+    Stopwords(Language),
+    CustomStopwords(Vec<String>),
+    Stem(Language),
+    KanaFolding,
+    Width,
+    LongVowel { expand: bool },
+    Romaji { keep_original: bool },
+}
+
+impl AnalyzerConfig {
+    /// Creates a new config using `tokenizer` without any filters.
+    #[inline]
+    pub fn new(tokenizer: TokenizerConfig) -> Self {
+        Self {
+            tokenizer,
+            filters: vec![],
+        }
+    }
+
+    /// Config for texts in `language`: Splits into words, lowercases, removes the languages
+    /// stopwords and stems the remaining words.
+    pub fn for_language(language: Language) -> Self {
+        Self::new(TokenizerConfig::Word { allowed: vec![] })
+            .with_filter(FilterConfig::Lowercase)
+            .with_filter(FilterConfig::Stopwords(language))
+            .with_filter(FilterConfig::Stem(language))
+    }
+
+    /// Appends a filter. Filters are applied in the order they were added.
+    #[inline]
+    pub fn with_filter(mut self, filter: FilterConfig) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Builds the analyzer described by the config.
+    pub fn build(&self) -> Analyzer {
+        let mut analyzer = match &self.tokenizer {
+            TokenizerConfig::Whitespace => Analyzer::new(WhitespaceTokenizer),
+            TokenizerConfig::Word { allowed } => {
+                Analyzer::new(WordTokenizer::new().with_allowed(allowed))
+            }
+            TokenizerConfig::CjkBigram { allowed } => Analyzer::new(
+                CjkBigramTokenizer::new()
+                    .with_word_tokenizer(WordTokenizer::new().with_allowed(allowed)),
+            ),
+        };
+
+        for filter in self.filters.iter() {
+            analyzer = match filter {
+                FilterConfig::Lowercase => analyzer.with_filter(LowercaseFilter),
+                FilterConfig::Punctuation => analyzer.with_filter(PunctuationFilter),
+                FilterConfig::Stopwords(language) => {
+                    analyzer.with_filter(StopwordFilter::for_language(*language))
+                }
+                FilterConfig::CustomStopwords(words) => {
+                    analyzer.with_filter(StopwordFilter::new(words.iter().cloned()))
+                }
+                FilterConfig::Stem(language) => analyzer.with_filter(StemFilter::new(*language)),
+                FilterConfig::KanaFolding => analyzer.with_filter(KanaFoldingFilter),
+                FilterConfig::Width => analyzer.with_filter(WidthFilter),
+                FilterConfig::LongVowel { expand } => {
+                    let mut f = LongVowelFilter::new();
+                    if *expand {
+                        f = f.with_expansion();
+                    }
+                    analyzer.with_filter(f)
+                }
+                FilterConfig::Romaji { keep_original } => {
+                    let mut f = RomajiFilter::new();
+                    if *keep_original {
+                        f = f.with_original();
+                    }
+                    analyzer.with_filter(f)
+                }
+            };
+        }
+
+        analyzer
+    }
+}
+
+impl Default for AnalyzerConfig {
+    /// Same as [`Analyzer::default()`].
+    #[inline]
+    fn default() -> Self {
+        Self::new(TokenizerConfig::Whitespace)
+            .with_filter(FilterConfig::Punctuation)
+            .with_filter(FilterConfig::Lowercase)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn build() {
+        let text = "Jotoba provides lots of free resources!";
+        assert_eq!(
+            AnalyzerConfig::default().build().analyze(text),
+            Analyzer::default().analyze(text)
+        );
+        assert_eq!(
+            AnalyzerConfig::for_language(Language::English)
+                .build()
+                .analyze(text),
+            ["jotoba", "provid", "lot", "free", "resourc"]
+        );
+
+        let config = AnalyzerConfig::for_language(Language::German);
+        let serialized = bitcode::serialize(&config).unwrap();
+        let deserialized: AnalyzerConfig = bitcode::deserialize(&serialized).unwrap();
+        assert_eq!(config, deserialized);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::analysis::language::Language;
+use std::collections::HashSet;
+
+/// Modifies the tokens of a text. Filters can change, remove or add tokens.
+pub trait TokenFilter: Send + Sync {
+    fn filter(&self, tokens: &mut Vec<String>);
+}
+
+/// Lowercases all tokens.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LowercaseFilter;
+
+impl TokenFilter for LowercaseFilter {
+    #[inline]
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            if token.chars().any(char::is_uppercase) {
+                *token = token.to_lowercase();
+            }
+        }
+    }
+}
+
+/// Strips punctuation from the start and end of all tokens and removes tokens that only consisted
+/// of punctuation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PunctuationFilter;
+
+impl TokenFilter for PunctuationFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            let trimmed = token.trim_matches(is_punctuation);
+            if trimmed.len() != token.len() {
+                *token = trimmed.to_string();
+            }
+        }
+        tokens.retain(|i| !i.is_empty());
+    }
+}
+
+/// Returns `true` if `c` is an ASCII, general or CJK punctuation character.
+#[inline]
+pub(crate) fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(c, '\u{2000}'..='\u{206F}' | '\u{3000}'..='\u{303F}' | '\u{FF01}'..='\u{FF0F}')
+}
+
+/// Removes all tokens that are in a list of stopwords.
+#[derive(Debug, Clone, Default)]
+pub struct StopwordFilter {
+    stopwords: HashSet<String>,
+}
+
+impl StopwordFilter {
+    pub fn new<I, T>(stopwords: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let stopwords = stopwords.into_iter().map(|i| i.into()).collect();
+        Self { stopwords }
+    }
+
+    /// Creates a filter removing the built-in stopwords of `language`.
+    #[inline]
+    pub fn for_language(language: Language) -> Self {
+        Self::new(language.stopwords().iter().copied())
+    }
+}
+
+impl TokenFilter for StopwordFilter {
+    #[inline]
+    fn filter(&self, tokens: &mut Vec<String>) {
+        tokens.retain(|i| !self.stopwords.contains(i));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn filtered<F: TokenFilter>(filter: F, tokens: &[&str]) -> Vec<String> {
+        let mut tokens = tokens.iter().map(|i| i.to_string()).collect();
+        filter.filter(&mut tokens);
+        tokens
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(
+            filtered(LowercaseFilter, &["Jotoba", "ÄB", "a"]),
+            ["jotoba", "äb", "a"]
+        );
+        assert_eq!(
+            filtered(
+                PunctuationFilter,
+                &["words,", "(Joto-kun)", "!", "漢字。", "you're"]
+            ),
+            ["words", "Joto-kun", "漢字", "you're"]
+        );
+        assert_eq!(
+            filtered(
+                StopwordFilter::new(["a", "the"]),
+                &["a", "kanji", "the", "radical"]
+            ),
+            ["kanji", "radical"]
+        );
+        assert_eq!(
+            filtered(
+                StopwordFilter::for_language(Language::English),
+                &["the", "kanji", "of", "a", "radical"]
+            ),
+            ["kanji", "radical"]
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use crate::analysis::filter::TokenFilter;
+
+/// Converts katakana to hiragana so both scripts match the same terms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KanaFoldingFilter;
+
+impl TokenFilter for KanaFoldingFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            if token.chars().any(is_foldable_katakana) {
+                *token = token.chars().map(katakana_to_hiragana).collect();
+            }
+        }
+    }
+}
+
+/// Returns `true` if `c` is a katakana that has a hiragana counterpart.
+#[inline]
+fn is_foldable_katakana(c: char) -> bool {
+    ('\u{30A1}'..='\u{30F6}').contains(&c) || c == '\u{30FD}' || c == '\u{30FE}'
+}
+
+/// Returns the hiragana of a katakana or `c` if it isn't a katakana.
+#[inline]
+fn katakana_to_hiragana(c: char) -> char {
+    if is_foldable_katakana(c) {
+        char::from_u32(c as u32 - 0x60).unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+/// Normalizes the character width of all tokens. Half-width katakana are converted to their
+/// full-width form, with separate (semi-)voiced sound marks being combined with the preceding
+/// kana. Full-width ASCII letters, digits and symbols are converted to ASCII.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WidthFilter;
+
+impl TokenFilter for WidthFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            if token
+                .chars()
+                .any(|c| ('\u{FF01}'..='\u{FF9F}').contains(&c))
+            {
+                *token = normalize_width(token);
+            }
+        }
+    }
+}
+
+/// Full-width katakana for the half-width katakana U+FF61 to U+FF9D.
+#[rustfmt::skip]
+const HALFWIDTH_KANA: [char; 61] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン',
+];
+
+fn normalize_width(token: &str) -> String {
+    let mut out = String::with_capacity(token.len());
+    for c in token.chars() {
+        match c {
+            '\u{FF01}'..='\u{FF5E}' => out.push(char::from_u32(c as u32 - 0xFEE0).unwrap()),
+            '\u{FF61}'..='\u{FF9D}' => out.push(HALFWIDTH_KANA[(c as u32 - 0xFF61) as usize]),
+            // Voiced sound mark
+            '\u{FF9E}' => match out.pop() {
+                Some(prev) => push_marked(&mut out, prev, 1),
+                None => out.push('゛'),
+            },
+            // Semi-voiced sound mark
+            '\u{FF9F}' => match out.pop() {
+                Some(prev) => push_marked(&mut out, prev, 2),
+                None => out.push('゜'),
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Pushes `kana` combined with a (semi-)voiced sound mark. In unicode the voiced form follows the
+/// plain form by `offset`.
+fn push_marked(out: &mut String, kana: char, offset: u32) {
+    let combinable = match offset {
+        1 => "カキクケコサシスセソタチツテトハヒフヘホ".contains(kana),
+        _ => "ハヒフヘホ".contains(kana),
+    };
+    if combinable {
+        out.push(char::from_u32(kana as u32 + offset).unwrap());
+    } else if kana == 'ウ' && offset == 1 {
+        out.push('ヴ');
+    } else {
+        out.push(kana);
+        out.push(if offset == 1 { '゛' } else { '゜' });
+    }
+}
+
+/// Normalizes long vowel marks. Variants like `〜` or `-` that follow a kana are replaced with
+/// `ー`. Optionally the mark can be replaced by the vowel it extends, so `らーめん` and
+/// `らあめん` match the same term.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LongVowelFilter {
+    expand: bool,
+}
+
+impl LongVowelFilter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces long vowel marks that follow a hiragana with the vowel they extend.
+    pub fn with_expansion(mut self) -> Self {
+        self.expand = true;
+        self
+    }
+}
+
+impl TokenFilter for LongVowelFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            if token.chars().any(is_long_vowel_mark) {
+                *token = self.normalize(token);
+            }
+        }
+    }
+}
+
+impl LongVowelFilter {
+    fn normalize(&self, token: &str) -> String {
+        let mut out = String::with_capacity(token.len());
+        let mut prev: Option<char> = None;
+        for c in token.chars() {
+            let c = match prev {
+                Some(p) if is_long_vowel_mark(c) && is_kana(p) && self.expand => {
+                    vowel_of(p).unwrap_or('ー')
+                }
+                Some(p) if is_long_vowel_mark(c) && is_kana(p) => 'ー',
+                _ => c,
+            };
+            out.push(c);
+            prev = Some(c);
+        }
+        out
+    }
+}
+
+#[inline]
+fn is_long_vowel_mark(c: char) -> bool {
+    matches!(c, 'ー' | 'ｰ' | '〜' | '～' | '-' | '－' | '‐' | '—' | '―')
+}
+
+#[inline]
+fn is_kana(c: char) -> bool {
+    ('\u{3041}'..='\u{3096}').contains(&c) || ('\u{30A1}'..='\u{30FA}').contains(&c)
+}
+
+/// Returns the vowel (as hiragana) of a hiragana.
+fn vowel_of(kana: char) -> Option<char> {
+    const ROWS: [(char, &str); 5] = [
+        ('あ', "あぁかがさざただなはばぱまやゃらわゎ"),
+        ('い', "いぃきぎしじちぢにひびぴみりゐ"),
+        ('う', "うぅくぐすずつっづぬふぶぷむゆゅるゔ"),
+        ('え', "えぇけげせぜてでねへべぺめれゑ"),
+        ('お', "おぉこごそぞとどのほぼぽもよょろを"),
+    ];
+    ROWS.iter()
+        .find(|(_, kana_row)| kana_row.contains(kana))
+        .map(|(vowel, _)| *vowel)
+}
+
+/// Converts tokens written in romaji (Hepburn or Kunrei-shiki) to hiragana, so romaji queries
+/// can match kana terms. Tokens that can't be converted completely stay as they are.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RomajiFilter {
+    keep_original: bool,
+}
+
+impl RomajiFilter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the romaji token in addition to its kana, so romaji words can still be found as
+    /// they are.
+    pub fn with_original(mut self) -> Self {
+        self.keep_original = true;
+        self
+    }
+}
+
+impl TokenFilter for RomajiFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        let mut out = Vec::with_capacity(tokens.len());
+        for token in tokens.drain(..) {
+            match romaji_to_kana(&token) {
+                Some(kana) => {
+                    out.push(kana);
+                    if self.keep_original {
+                        out.push(token);
+                    }
+                }
+                None => out.push(token),
+            }
+        }
+        *tokens = out;
+    }
+}
+
+/// Romaji syllables and their hiragana. Longer syllables have to be matched first.
+#[rustfmt::skip]
+const ROMAJI: &[(&str, &str)] = &[
+    ("kya", "きゃ"), ("kyu", "きゅ"), ("kyo", "きょ"), ("gya", "ぎゃ"), ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"), ("sha", "しゃ"), ("shi", "し"), ("shu", "しゅ"), ("she", "しぇ"),
+    ("sho", "しょ"), ("sya", "しゃ"), ("syu", "しゅ"), ("syo", "しょ"), ("cha", "ちゃ"),
+    ("chi", "ち"), ("chu", "ちゅ"), ("che", "ちぇ"), ("cho", "ちょ"), ("tya", "ちゃ"),
+    ("tyu", "ちゅ"), ("tyo", "ちょ"), ("tsu", "つ"), ("nya", "にゃ"), ("nyu", "にゅ"),
+    ("nyo", "にょ"), ("hya", "ひゃ"), ("hyu", "ひゅ"), ("hyo", "ひょ"), ("bya", "びゃ"),
+    ("byu", "びゅ"), ("byo", "びょ"), ("pya", "ぴゃ"), ("pyu", "ぴゅ"), ("pyo", "ぴょ"),
+    ("mya", "みゃ"), ("myu", "みゅ"), ("myo", "みょ"), ("rya", "りゃ"), ("ryu", "りゅ"),
+    ("ryo", "りょ"), ("zya", "じゃ"), ("zyu", "じゅ"), ("zyo", "じょ"), ("ja", "じゃ"),
+    ("ji", "じ"), ("ju", "じゅ"), ("je", "じぇ"), ("jo", "じょ"), ("ka", "か"), ("ki", "き"),
+    ("ku", "く"), ("ke", "け"), ("ko", "こ"), ("ga", "が"), ("gi", "ぎ"), ("gu", "ぐ"),
+    ("ge", "げ"), ("go", "ご"), ("sa", "さ"), ("si", "し"), ("su", "す"), ("se", "せ"),
+    ("so", "そ"), ("za", "ざ"), ("zi", "じ"), ("zu", "ず"), ("ze", "ぜ"), ("zo", "ぞ"),
+    ("ta", "た"), ("ti", "ち"), ("tu", "つ"), ("te", "て"), ("to", "と"), ("da", "だ"),
+    ("di", "ぢ"), ("du", "づ"), ("de", "で"), ("do", "ど"), ("na", "な"), ("ni", "に"),
+    ("nu", "ぬ"), ("ne", "ね"), ("no", "の"), ("ha", "は"), ("hi", "ひ"), ("fu", "ふ"),
+    ("hu", "ふ"), ("he", "へ"), ("ho", "ほ"), ("ba", "ば"), ("bi", "び"), ("bu", "ぶ"),
+    ("be", "べ"), ("bo", "ぼ"), ("pa", "ぱ"), ("pi", "ぴ"), ("pu", "ぷ"), ("pe", "ぺ"),
+    ("po", "ぽ"), ("ma", "ま"), ("mi", "み"), ("mu", "む"), ("me", "め"), ("mo", "も"),
+    ("ya", "や"), ("yu", "ゆ"), ("yo", "よ"), ("ra", "ら"), ("ri", "り"), ("ru", "る"),
+    ("re", "れ"), ("ro", "ろ"), ("wa", "わ"), ("wo", "を"), ("a", "あ"),
+    ("i", "い"), ("u", "う"), ("e", "え"), ("o", "お"), ("-", "ー"),
+];
+
+/// Converts a romaji word to hiragana. Returns `None` if the word contains anything that isn't
+/// romaji.
+pub(crate) fn romaji_to_kana(word: &str) -> Option<String> {
+    // Long vowels written with macrons.
+    let word = word
+        .to_lowercase()
+        .replace('ā', "aa")
+        .replace('ī', "ii")
+        .replace('ū', "uu")
+        .replace('ē', "ee")
+        .replace('ō', "ou");
+    if word.is_empty() || !word.chars().any(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut out = String::with_capacity(word.len() * 3);
+    let mut rest = word.as_str();
+    while !rest.is_empty() {
+        let mut chars = rest.chars();
+        let first = chars.next()?;
+        let second = chars.next();
+
+        // Doubled consonants are written with a small tsu.
+        if second == Some(first) && first.is_ascii_alphabetic() && !"aiueon".contains(first) {
+            out.push('っ');
+            rest = &rest[1..];
+            continue;
+        }
+        // "tch" as in "matcha".
+        if rest.starts_with("tch") {
+            out.push('っ');
+            rest = &rest[1..];
+            continue;
+        }
+
+        if let Some((romaji, kana)) = ROMAJI.iter().find(|(r, _)| rest.starts_with(r)) {
+            out.push_str(kana);
+            rest = &rest[romaji.len()..];
+            continue;
+        }
+
+        // An n that isn't followed by a vowel or y. A following apostrophe or n that isn't
+        // followed by a vowel either belongs to the same ん.
+        if first == 'n' {
+            out.push('ん');
+            let third = rest[1..].chars().nth(1);
+            rest = match second {
+                Some('\'') => &rest[2..],
+                Some('n') if !third.map(is_vowel_or_y).unwrap_or(false) => &rest[2..],
+                _ => &rest[1..],
+            };
+            continue;
+        }
+
+        return None;
+    }
+
+    Some(out)
+}
+
+#[inline]
+fn is_vowel_or_y(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o' | 'y')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::analysis::tokenizer::WhitespaceTokenizer;
+    use crate::analysis::Analyzer;
+
+    fn filtered<F: TokenFilter>(filter: F, tokens: &[&str]) -> Vec<String> {
+        let mut tokens = tokens.iter().map(|i| i.to_string()).collect();
+        filter.filter(&mut tokens);
+        tokens
+    }
+
+    #[test]
+    fn kana_folding() {
+        assert_eq!(
+            filtered(
+                KanaFoldingFilter,
+                &["カタカナ", "ひらがな", "ラーメン", "漢字"]
+            ),
+            ["かたかな", "ひらがな", "らーめん", "漢字"]
+        );
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(
+            filtered(WidthFilter, &["ｶﾀｶﾅ", "ｶﾞｯｺｳ", "ﾊﾟﾝ", "ＡＢＣ１２３", "ｳﾞ"]),
+            ["カタカナ", "ガッコウ", "パン", "ABC123", "ヴ"]
+        );
+    }
+
+    #[test]
+    fn long_vowel() {
+        let tokens = &["らーめん", "ら〜めん", "ラｰメン", "a-b"];
+        assert_eq!(
+            filtered(LongVowelFilter::new(), tokens),
+            ["らーめん", "らーめん", "ラーメン", "a-b"]
+        );
+        assert_eq!(
+            filtered(LongVowelFilter::new().with_expansion(), tokens),
+            ["らあめん", "らあめん", "ラーメン", "a-b"]
+        );
+    }
+
+    #[test]
+    fn romaji() {
+        let cases = [
+            ("kanji", "かんじ"),
+            ("Kana", "かな"),
+            ("gakkou", "がっこう"),
+            ("shinbun", "しんぶん"),
+            ("kon'ya", "こんや"),
+            ("konnichiha", "こんにちは"),
+            ("hon", "ほん"),
+            ("minna", "みんな"),
+            ("matcha", "まっちゃ"),
+            ("tōkyō", "とうきょう"),
+            ("jotoba", "じょとば"),
+        ];
+        for (romaji, kana) in cases {
+            assert_eq!(romaji_to_kana(romaji).as_deref(), Some(kana), "{romaji}");
+        }
+        assert_eq!(romaji_to_kana("xyz"), None);
+        assert_eq!(romaji_to_kana("漢字"), None);
+        assert_eq!(romaji_to_kana("123"), None);
+
+        assert_eq!(
+            filtered(
+                RomajiFilter::new().with_original(),
+                &["kanji", "漢字", "xyz"]
+            ),
+            ["かんじ", "kanji", "漢字", "xyz"]
+        );
+    }
+
+    #[test]
+    fn japanese_analyzer() {
+        let analyzer = Analyzer::new(WhitespaceTokenizer)
+            .with_filter(WidthFilter)
+            .with_filter(RomajiFilter::new())
+            .with_filter(KanaFoldingFilter)
+            .with_filter(LongVowelFilter::new().with_expansion());
+
+        assert_eq!(analyzer.analyze("kanji"), analyzer.analyze("かんじ"));
+        assert_eq!(analyzer.analyze("ｶﾝｼﾞ"), analyzer.analyze("かんじ"));
+        assert_eq!(analyzer.analyze("ラーメン"), analyzer.analyze("raamen"));
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// Languages with built-in stopword lists and stemmers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    English,
+    German,
+}
+
+impl Language {
+    /// Returns the stopwords of the language.
+    #[inline]
+    pub fn stopwords(&self) -> &'static [&'static str] {
+        match self {
+            Language::English => ENGLISH_STOPWORDS,
+            Language::German => GERMAN_STOPWORDS,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn stem_algorithm(&self) -> rust_stemmers::Algorithm {
+        match self {
+            Language::English => rust_stemmers::Algorithm::English,
+            Language::German => rust_stemmers::Algorithm::German,
+        }
+    }
+}
+
+#[rustfmt::skip]
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "am", "an", "and", "any", "are",
+    "as", "at", "be", "because", "been", "before", "being", "below", "between", "both", "but",
+    "by", "can", "could", "did", "do", "does", "doing", "down", "during", "each", "few", "for",
+    "from", "further", "had", "has", "have", "having", "he", "her", "here", "hers", "herself",
+    "him", "himself", "his", "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just",
+    "me", "more", "most", "my", "myself", "no", "nor", "not", "now", "of", "off", "on", "once",
+    "only", "or", "other", "our", "ours", "ourselves", "out", "over", "own", "same", "she",
+    "should", "so", "some", "such", "than", "that", "the", "their", "theirs", "them",
+    "themselves", "then", "there", "these", "they", "this", "those", "through", "to", "too",
+    "under", "until", "up", "very", "was", "we", "were", "what", "when", "where", "which",
+    "while", "who", "whom", "why", "will", "with", "would", "you", "your", "yours", "yourself",
+    "yourselves",
+];
+
+#[rustfmt::skip]
+const GERMAN_STOPWORDS: &[&str] = &[
+    "aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "ander",
+    "andere", "anderem", "anderen", "anderer", "anderes", "auch", "auf", "aus", "bei", "bin",
+    "bis", "bist", "da", "damit", "dann", "das", "dass", "dasselbe", "dazu", "dein", "deine",
+    "deinem", "deinen", "deiner", "dem", "demselben", "den", "denn", "denselben", "der",
+    "derer", "derselbe", "derselben", "des", "desselben", "dessen", "dich", "die", "dies",
+    "diese", "dieselbe", "dieselben", "diesem", "diesen", "dieser", "dieses", "dir", "doch",
+    "dort", "du", "durch", "ein", "eine", "einem", "einen", "einer", "eines", "einig", "einige",
+    "einigem", "einigen", "einiger", "einiges", "einmal", "er", "es", "etwas", "euch", "euer",
+    "eure", "eurem", "euren", "eurer", "für", "gegen", "gewesen", "hab", "habe", "haben", "hat",
+    "hatte", "hatten", "hier", "hin", "hinter", "ich", "ihm", "ihn", "ihnen", "ihr", "ihre",
+    "ihrem", "ihren", "ihrer", "im", "in", "indem", "ins", "ist", "jede", "jedem", "jeden",
+    "jeder", "jedes", "jene", "jenem", "jenen", "jener", "jenes", "jetzt", "kann", "kein",
+    "keine", "keinem", "keinen", "keiner", "können", "könnte", "machen", "man", "manche",
+    "manchem", "manchen", "mancher", "manches", "mein", "meine", "meinem", "meinen", "meiner",
+    "mich", "mir", "mit", "muss", "musste", "nach", "nicht", "nichts", "noch", "nun", "nur",
+    "ob", "oder", "ohne", "sehr", "sein", "seine", "seinem", "seinen", "seiner", "selbst",
+    "sich", "sie", "sind", "so", "solche", "solchem", "solchen", "solcher", "solches", "soll",
+    "sollte", "sondern", "sonst", "über", "um", "und", "uns", "unser", "unsere", "unserem",
+    "unseren", "unserer", "unter", "viel", "vom", "von", "vor", "während", "war", "waren",
+    "warst", "was", "weg", "weil", "weiter", "welche", "welchem", "welchen", "welcher",
+    "welches", "wenn", "werde", "werden", "wie", "wieder", "will", "wir", "wird", "wirst", "wo",
+    "wollen", "wollte", "würde", "würden", "zu", "zum", "zur", "zwar", "zwischen",
+];
@@ -0,0 +1,175 @@
+pub mod config;
+pub mod filter;
+pub mod japanese;
+pub mod language;
+pub mod stem;
+pub mod tokenizer;
+
+use crate::edit::NewItem;
+use filter::{LowercaseFilter, PunctuationFilter, TokenFilter};
+use std::sync::Arc;
+use tokenizer::{Tokenizer, WhitespaceTokenizer};
+
+/// Turns texts into index terms by splitting them with a tokenizer and passing the tokens through
+/// a chain of token filters. The same analyzer has to be used for building an index and querying
+/// it, so both produce the same terms. Analyzers are cheap to clone.
+#[derive(Clone)]
+pub struct Analyzer {
+    tokenizer: Arc<dyn Tokenizer>,
+    filters: Vec<Arc<dyn TokenFilter>>,
+}
+
+impl Analyzer {
+    /// Creates a new analyzer using `tokenizer` without any filters.
+    #[inline]
+    pub fn new<T: Tokenizer + 'static>(tokenizer: T) -> Self {
+        Self {
+            tokenizer: Arc::new(tokenizer),
+            filters: vec![],
+        }
+    }
+
+    /// Appends a filter. Filters are applied in the order they were added.
+    pub fn with_filter<F: TokenFilter + 'static>(mut self, filter: F) -> Self {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Returns the terms of `text`.
+    pub fn analyze(&self, text: &str) -> Vec<String> {
+        let mut tokens = self.tokenizer.tokenize(text);
+        for filter in self.filters.iter() {
+            if tokens.is_empty() {
+                break;
+            }
+            filter.filter(&mut tokens);
+        }
+        tokens
+    }
+
+    /// Returns a new insertion item with the terms of `text`.
+    #[inline]
+    pub fn new_item<T, S>(&self, text: &str, store_item: S) -> NewItem<T, S>
+    where
+        T: From<String>,
+    {
+        let terms = self.analyze(text).into_iter().map(T::from).collect();
+        NewItem::new(terms, store_item)
+    }
+
+    /// Returns a new insertion item with all terms of `text` that can be converted into `T`. Other
+    /// terms are skipped. Useful for fixed size terms like ngrams.
+    #[inline]
+    pub fn try_new_item<T, S>(&self, text: &str, store_item: S) -> NewItem<T, S>
+    where
+        T: TryFrom<String>,
+    {
+        let terms = self
+            .analyze(text)
+            .into_iter()
+            .filter_map(|i| T::try_from(i).ok())
+            .collect();
+        NewItem::new(terms, store_item)
+    }
+}
+
+/// Index terms that can be turned back into text. Used to run multi-word synonyms through the
+/// analyzer when they get added to a query.
+pub trait TermText {
+    /// Returns the text of the term.
+    fn text(&self) -> String;
+}
+
+impl TermText for String {
+    #[inline]
+    fn text(&self) -> String {
+        self.clone()
+    }
+}
+
+impl Default for Analyzer {
+    /// Splits texts at whitespace, strips punctuation and lowercases all terms.
+    #[inline]
+    fn default() -> Self {
+        Self::new(WhitespaceTokenizer)
+            .with_filter(PunctuationFilter)
+            .with_filter(LowercaseFilter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::ngram::mixed::term::MixedNgram;
+    use crate::index::dict::IndexDictionary;
+    use crate::index::preset::{DefaultIndex, MixedNgramIndex};
+    use crate::retrieve::build::RetrieverBuilder;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::Creatable;
+    use filter::StopwordFilter;
+    use tokenizer::{CjkBigramTokenizer, WordTokenizer};
+
+    const CJK_TEXTS: [&str; 4] = ["日本語の辞書", "漢字辞典", "日本", "Jotoba 辞書"];
+
+    #[test]
+    fn analyzer() {
+        let text = "Use Kanji, Kana or Romaji to search for radicals!";
+        assert_eq!(
+            Analyzer::default().analyze(text),
+            ["use", "kanji", "kana", "or", "romaji", "to", "search", "for", "radicals"]
+        );
+
+        let analyzer = Analyzer::new(WordTokenizer::new())
+            .with_filter(LowercaseFilter)
+            .with_filter(StopwordFilter::new(["or", "to", "for"]));
+        assert_eq!(
+            analyzer.analyze(text),
+            ["use", "kanji", "kana", "romaji", "search", "radicals"]
+        );
+
+        let item: NewItem<String, u32> = analyzer.new_item("Kana or Kanji", 1);
+        assert_eq!(item.terms(), ["kana", "kanji"]);
+    }
+
+    #[test]
+    fn cjk_index() {
+        let analyzer = Analyzer::new(CjkBigramTokenizer::new()).with_filter(LowercaseFilter);
+
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DefaultIndex<_, String, u32> = DefaultIndex::create(backend).unwrap();
+        let mut editor = index.editor().with_analyzer(analyzer.clone());
+        for (i, text) in CJK_TEXTS.iter().enumerate() {
+            editor.insert_text(text, i as u32).unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+        assert!(index.dict().has_term(&"jotoba".to_string()));
+
+        let count = |query: &str| {
+            let mut retrieve_builder =
+                RetrieverBuilder::new(&index).with_analyzer(analyzer.clone());
+            retrieve_builder.add_text::<String>(query);
+            retrieve_builder.count_intersection()
+        };
+        assert_eq!(count("辞書"), 2);
+        assert_eq!(count("日本語"), 1);
+        assert_eq!(count("jotoba"), 1);
+
+        // Bigrams and unigrams fit into mixed ngram indexes.
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: MixedNgramIndex<_, u64, 2> = MixedNgramIndex::create(backend).unwrap();
+        let mut editor = index.editor();
+        for (i, text) in CJK_TEXTS.iter().enumerate() {
+            editor
+                .insert(analyzer.try_new_item(text, i as u64))
+                .unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let term = MixedNgram::try_from("辞書").unwrap();
+        let mut retrieve_builder = RetrieverBuilder::new(&index);
+        retrieve_builder.add_term(&term);
+        assert_eq!(retrieve_builder.count_union(), 2);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::analysis::filter::TokenFilter;
+use crate::analysis::language::Language;
+use rust_stemmers::Stemmer;
+
+/// Reduces all tokens to their stem using the Snowball stemmer of a language, so for example
+/// "resources" and "resource" become the same term. Tokens should be lowercased before.
+pub struct StemFilter {
+    stemmer: Stemmer,
+}
+
+impl StemFilter {
+    #[inline]
+    pub fn new(language: Language) -> Self {
+        Self {
+            stemmer: Stemmer::create(language.stem_algorithm()),
+        }
+    }
+}
+
+impl TokenFilter for StemFilter {
+    fn filter(&self, tokens: &mut Vec<String>) {
+        for token in tokens.iter_mut() {
+            let stemmed = self.stemmer.stem(token);
+            if stemmed.len() != token.len() || stemmed != token.as_str() {
+                *token = stemmed.into_owned();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stemmed(language: Language, tokens: &[&str]) -> Vec<String> {
+        let mut tokens = tokens.iter().map(|i| i.to_string()).collect();
+        StemFilter::new(language).filter(&mut tokens);
+        tokens
+    }
+
+    #[test]
+    fn stem() {
+        assert_eq!(
+            stemmed(Language::English, &["resources", "resource", "searching"]),
+            ["resourc", "resourc", "search"]
+        );
+        assert_eq!(
+            stemmed(Language::German, &["Wörterbücher", "wörterbuch"]),
+            stemmed(Language::German, &["wörterbuch", "wörterbuch"])
+        );
+    }
+}
@@ -0,0 +1,186 @@
+/// Splits a text into tokens.
+pub trait Tokenizer: Send + Sync {
+    /// Returns all tokens of `text` in the order they appear in.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// Splits texts at whitespace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    #[inline]
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split_whitespace().map(|i| i.to_string()).collect()
+    }
+}
+
+/// Splits texts at every character that is neither alphanumeric nor one of the additionally
+/// allowed characters.
+#[derive(Debug, Clone, Default)]
+pub struct WordTokenizer {
+    allowed: Vec<char>,
+}
+
+impl WordTokenizer {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the given characters within words, for example `'` for "you're" or `-` for compound
+    /// words.
+    pub fn with_allowed(mut self, allowed: &[char]) -> Self {
+        self.allowed.extend_from_slice(allowed);
+        self
+    }
+
+    #[inline]
+    fn is_word_char(&self, c: char) -> bool {
+        c.is_alphanumeric() || self.allowed.contains(&c)
+    }
+}
+
+impl Tokenizer for WordTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split(|c| !self.is_word_char(c))
+            .filter(|i| !i.is_empty())
+            .map(|i| i.to_string())
+            .collect()
+    }
+}
+
+/// Splits runs of CJK characters into overlapping character bigrams since CJK text isn't separated
+/// by spaces. Runs of a single CJK character are emitted as unigram. All other text is split by a
+/// word tokenizer.
+#[derive(Debug, Clone, Default)]
+pub struct CjkBigramTokenizer {
+    word_tokenizer: WordTokenizer,
+}
+
+impl CjkBigramTokenizer {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the tokenizer for non CJK text.
+    pub fn with_word_tokenizer(mut self, word_tokenizer: WordTokenizer) -> Self {
+        self.word_tokenizer = word_tokenizer;
+        self
+    }
+
+    /// Appends the bigrams of a run of CJK characters to `out`.
+    fn push_bigrams(run: &[char], out: &mut Vec<String>) {
+        if run.len() == 1 {
+            out.push(run[0].to_string());
+            return;
+        }
+        out.extend(run.windows(2).map(|w| w.iter().collect::<String>()));
+    }
+}
+
+impl Tokenizer for CjkBigramTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let mut out = vec![];
+        let mut run: Vec<char> = vec![];
+        let mut other_start = 0;
+
+        for (pos, c) in text.char_indices() {
+            if is_cjk(c) {
+                if run.is_empty() {
+                    out.extend(self.word_tokenizer.tokenize(&text[other_start..pos]));
+                }
+                run.push(c);
+            } else if !run.is_empty() {
+                Self::push_bigrams(&run, &mut out);
+                run.clear();
+                other_start = pos;
+            }
+        }
+
+        if run.is_empty() {
+            out.extend(self.word_tokenizer.tokenize(&text[other_start..]));
+        } else {
+            Self::push_bigrams(&run, &mut out);
+        }
+
+        out
+    }
+}
+
+/// Returns `true` if `c` is a Han, Hiragana, Katakana or Hangul character.
+#[inline]
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{309F}' // Hiragana
+        | '\u{30A0}'..='\u{30FF}' // Katakana
+        | '\u{31F0}'..='\u{31FF}' // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}' // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}' // Half-width Katakana
+        | '\u{20000}'..='\u{2FA1F}' // CJK extensions B-F and compatibility supplement
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tokenize() {
+        let text = "  Jotoba is a  multilingual dictionary, isn't it?";
+        assert_eq!(
+            WhitespaceTokenizer.tokenize(text),
+            [
+                "Jotoba",
+                "is",
+                "a",
+                "multilingual",
+                "dictionary,",
+                "isn't",
+                "it?"
+            ]
+        );
+        assert_eq!(
+            WordTokenizer::new().tokenize(text),
+            [
+                "Jotoba",
+                "is",
+                "a",
+                "multilingual",
+                "dictionary",
+                "isn",
+                "t",
+                "it"
+            ]
+        );
+        assert_eq!(
+            WordTokenizer::new().with_allowed(&['\'']).tokenize(text),
+            [
+                "Jotoba",
+                "is",
+                "a",
+                "multilingual",
+                "dictionary",
+                "isn't",
+                "it"
+            ]
+        );
+        assert!(WordTokenizer::new().tokenize(" .,!").is_empty());
+    }
+
+    #[test]
+    fn cjk_bigrams() {
+        let tokenizer = CjkBigramTokenizer::new();
+        assert_eq!(
+            tokenizer.tokenize("日本語の辞書 Jotoba, 漢 kanji!"),
+            ["日本", "本語", "語の", "の辞", "辞書", "Jotoba", "漢", "kanji"]
+        );
+        assert_eq!(tokenizer.tokenize("abc漢字def"), ["abc", "漢字", "def"]);
+        assert_eq!(tokenizer.tokenize("ラーメン。"), ["ラー", "ーメ", "メン"]);
+        assert!(tokenizer.tokenize("").is_empty());
+    }
+}
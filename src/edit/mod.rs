@@ -1,7 +1,11 @@
+mod new_document;
 mod new_item;
 
+pub use crate::edit::new_document::NewDocument;
 pub use crate::edit::new_item::NewItem;
 
+use crate::analysis::Analyzer;
+use crate::error::Error;
 use crate::index::dict::{EditableIndexDictionary, IndexDictionaryEditor};
 use crate::index::posting::{EditableIndexPosting, IndexPostingEditor};
 use crate::index::storage::{EditableIndexStorage, IndexStorageEditor, StorageInsertionResult};
@@ -22,6 +26,8 @@ pub struct IndexEditor<'a, E, B, T, S> {
 
     // Options
     sorted_postings: bool,
+    analyzer: Analyzer,
+    posting_lists: Vec<String>,
 
     // Temporary insertion data.
     stored_items: Vec<S>,
@@ -41,6 +47,8 @@ impl<'a, E, B, T, S> IndexEditor<'a, E, B, T, S> {
         Self {
             index,
             sorted_postings: false,
+            analyzer: Analyzer::default(),
+            posting_lists: vec![],
             stored_items: vec![],
             terms: FnvHashMap::default(),
             post_map: FnvHashMap::default(),
@@ -54,6 +62,20 @@ impl<'a, E, B, T, S> IndexEditor<'a, E, B, T, S> {
         self
     }
 
+    /// Sets the analyzer that turns texts inserted with `insert_text` into terms. Queries should
+    /// use the same analyzer.
+    pub fn with_analyzer(mut self, analyzer: Analyzer) -> Self {
+        self.analyzer = analyzer;
+        self
+    }
+
+    /// Sets the posting list names of the index. The name at position `i` belongs to posting
+    /// list `i`.
+    pub fn with_posting_lists(mut self, names: Vec<String>) -> Self {
+        self.posting_lists = names;
+        self
+    }
+
     #[inline]
     pub fn pending_count(&self) -> usize {
         self.stored_items.len()
@@ -101,6 +123,120 @@ where
         Ok(true)
     }
 
+    /// Inserts the item into the posting lists with the given names. Fails with
+    /// [`Error::UnknownPostingList`] if the index doesn't have one of the posting lists, in which
+    /// case nothing gets inserted.
+    pub fn insert_in_named_postings(
+        &mut self,
+        new_item: NewItem<T, S>,
+        names: &[&str],
+    ) -> Result<bool> {
+        let postings = self.posting_ids(names)?;
+        self.insert_in_postings(new_item, &postings)
+    }
+
+    /// Inserts a text which gets turned into terms by the editors analyzer.
+    #[inline]
+    pub fn insert_text(&mut self, text: &str, store_item: S) -> Result<bool>
+    where
+        T: From<String>,
+    {
+        self.insert_text_in_postings(text, store_item, &[0])
+    }
+
+    /// Inserts a text which gets turned into terms by the editors analyzer into the given posting
+    /// lists.
+    pub fn insert_text_in_postings(
+        &mut self,
+        text: &str,
+        store_item: S,
+        postings: &[u16],
+    ) -> Result<bool>
+    where
+        T: From<String>,
+    {
+        let new_item = self.analyzer.new_item(text, store_item);
+        self.insert_in_postings(new_item, postings)
+    }
+
+    /// Inserts a text which gets turned into terms by the editors analyzer into the posting lists
+    /// with the given names.
+    pub fn insert_text_in_named_postings(
+        &mut self,
+        text: &str,
+        store_item: S,
+        names: &[&str],
+    ) -> Result<bool>
+    where
+        T: From<String>,
+    {
+        let postings = self.posting_ids(names)?;
+        self.insert_text_in_postings(text, store_item, &postings)
+    }
+
+    /// Inserts a document with multiple fields. The terms of each field go into the posting list
+    /// named like the field. Fails with [`Error::UnknownPostingList`] if the index doesn't have
+    /// one of the fields, in which case nothing gets inserted.
+    pub fn insert_document(&mut self, document: NewDocument<T, S>) -> Result<bool> {
+        let (fields, store_item) = document.into_parts();
+
+        let mut field_terms = Vec::with_capacity(fields.len());
+        for (name, terms) in fields {
+            let field_id = self.posting_id(&name)?;
+            if !terms.is_empty() {
+                field_terms.push((field_id, terms));
+            }
+        }
+        if field_terms.is_empty() {
+            return Ok(false);
+        }
+
+        let stored_item_id = self.insert_store_item_temp(store_item);
+        for (field_id, terms) in field_terms {
+            let temp_term_ids = self.insert_terms_temp(terms);
+            self.post_map
+                .entry((field_id, stored_item_id))
+                .or_default()
+                .extend(temp_term_ids);
+        }
+
+        Ok(true)
+    }
+
+    /// Inserts a document with the texts of multiple fields which get turned into terms by the
+    /// editors analyzer.
+    pub fn insert_text_document(&mut self, fields: &[(&str, &str)], store_item: S) -> Result<bool>
+    where
+        T: From<String>,
+    {
+        let mut document = NewDocument::new(store_item);
+        for (name, text) in fields {
+            let terms = self
+                .analyzer
+                .analyze(text)
+                .into_iter()
+                .map(T::from)
+                .collect();
+            document.add_field(*name, terms);
+        }
+        self.insert_document(document)
+    }
+
+    /// Returns the ID of the posting list `name`.
+    #[inline]
+    fn posting_id(&self, name: &str) -> Result<u16> {
+        self.posting_lists
+            .iter()
+            .position(|i| i == name)
+            .map(|i| i as u16)
+            .ok_or_else(|| Error::UnknownPostingList(name.to_string()))
+    }
+
+    /// Returns the IDs of the posting lists `names`.
+    fn posting_ids(&self, names: &[&str]) -> Result<Vec<u16>> {
+        names.iter().map(|i| self.posting_id(i)).collect()
+    }
+
     /// Stores the terms temporarily with a temp ID.
     #[inline]
     fn insert_terms_temp(&mut self, terms: Vec<T>) -> Vec<u32> {
@@ -184,6 +320,7 @@ where
                 term_id_map.insert(tmp_id, new_id);
                 sum += 1;
             }
+            term_edit.flush()?;
 
             term_id_map
         };
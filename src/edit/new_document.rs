@@ -0,0 +1,46 @@
+/// Index insertion item with multiple fields, each with its own terms. The terms of each field are
+/// inserted into the posting list of the field so queries can target single fields.
+#[derive(Clone, Debug)]
+pub struct NewDocument<T, S> {
+    fields: Vec<(String, Vec<T>)>,
+    store_item: S,
+}
+
+impl<T, S> NewDocument<T, S> {
+    /// Creates a new document without any fields.
+    #[inline]
+    pub fn new(store_item: S) -> Self {
+        Self {
+            fields: vec![],
+            store_item,
+        }
+    }
+
+    /// Adds a field with its terms.
+    #[inline]
+    pub fn with_field<N: Into<String>>(mut self, name: N, terms: Vec<T>) -> Self {
+        self.add_field(name, terms);
+        self
+    }
+
+    /// Adds a field with its terms.
+    #[inline]
+    pub fn add_field<N: Into<String>>(&mut self, name: N, terms: Vec<T>) {
+        self.fields.push((name.into(), terms));
+    }
+
+    #[inline]
+    pub fn fields(&self) -> &[(String, Vec<T>)] {
+        &self.fields
+    }
+
+    #[inline]
+    pub fn store_item(&self) -> &S {
+        &self.store_item
+    }
+
+    #[inline]
+    pub(crate) fn into_parts(self) -> (Vec<(String, Vec<T>)>, S) {
+        (self.fields, self.store_item)
+    }
+}
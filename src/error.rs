@@ -3,9 +3,13 @@ pub enum Error {
     Io(std::io::Error),
     Bitcode(bitcode::Error),
     Bincode(bincode::Error),
+    Fst(fst::Error),
+    Pattern(regex_automata::Error),
+    Lz4(lz4_flex::block::DecompressError),
     OutOfBounds,
     Internal,
     DuplicateEntry,
+    UnknownPostingList(String),
     UnsupportedOperation,
 }
 
@@ -25,6 +29,13 @@ impl From<bytestore::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
 impl From<bitcode::Error> for Error {
     #[inline]
     fn from(value: bitcode::Error) -> Self {
@@ -38,3 +49,24 @@ impl From<bincode::Error> for Error {
         Self::Bincode(value)
     }
 }
+
+impl From<fst::Error> for Error {
+    #[inline]
+    fn from(value: fst::Error) -> Self {
+        Self::Fst(value)
+    }
+}
+
+impl From<regex_automata::Error> for Error {
+    #[inline]
+    fn from(value: regex_automata::Error) -> Self {
+        Self::Pattern(value)
+    }
+}
+
+impl From<lz4_flex::block::DecompressError> for Error {
+    #[inline]
+    fn from(value: lz4_flex::block::DecompressError) -> Self {
+        Self::Lz4(value)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::analysis::TermText;
+use bytestore::components::map::hashing::Hash;
+use fnv::FnvHasher;
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+use std::hash::Hasher;
+
+/// An ngram of N UTF-8 bytes. Uses a quarter of the space of an `Ngram<N>` for ASCII text but
+/// ngrams of non ASCII text can start or end within a character.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bytegram<const N: usize>([u8; N]);
+
+impl<const N: usize> Bytegram<N> {
+    /// Returns the bytes of the bytegram.
+    #[inline]
+    pub fn bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> TermText for Bytegram<N> {
+    /// Bytes that aren't valid UTF-8 get replaced.
+    #[inline]
+    fn text(&self) -> String {
+        String::from_utf8_lossy(&self.0).into_owned()
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Bytegram<N> {
+    #[inline]
+    fn from(value: [u8; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for Bytegram<N> {
+    type Error = ();
+
+    /// Takes the first N bytes. Fails if there are less than N bytes.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let b: [u8; N] = value.get(..N).ok_or(())?.try_into().map_err(|_| ())?;
+        Ok(Self(b))
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for Bytegram<N> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl<const N: usize> TryFrom<&String> for Bytegram<N> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl<const N: usize> TryFrom<String> for Bytegram<N> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl<const N: usize> Serialize for Bytegram<N> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(N)?;
+        for item in self.0.iter() {
+            s.serialize_element(item)?;
+        }
+        s.end()
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Bytegram<N> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let res = deserializer.deserialize_tuple(N, ByteArrayVisitor::<N>)?;
+        Ok(Self(res))
+    }
+}
+
+struct ByteArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ByteArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str(&format!("byte array of length {}", N))
+    }
+
+    #[inline]
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut out = [0u8; N];
+        for item in out.iter_mut() {
+            *item = seq
+                .next_element::<u8>()?
+                .ok_or_else(|| serde::de::Error::invalid_length(N, &self))?;
+        }
+        Ok(out)
+    }
+}
+
+impl<const N: usize> Hash for Bytegram<N> {
+    #[inline]
+    fn hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write(&self.0);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bytegram_deser() {
+        let bytegram = Bytegram::<3>::try_from("abc").unwrap();
+        let serialized = serde_json::to_string(&bytegram).unwrap();
+        assert_eq!(serialized, "[97,98,99]");
+        let deserialized: Bytegram<3> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, bytegram);
+
+        let serialized = bitcode::serialize(&bytegram).unwrap();
+        let deserialized: Bytegram<3> = bitcode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, bytegram);
+
+        assert!(Bytegram::<3>::try_from("ab").is_err());
+        assert_eq!(Bytegram::<2>::try_from("abc").unwrap().bytes(), b"ab");
+    }
+}
@@ -0,0 +1,46 @@
+use super::Bytegram;
+use super::BytegramDict;
+use crate::index::dict::IndexDictionaryEditor;
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+
+pub struct DictEditor<'a, B, const N: usize> {
+    bytegram_dict: &'a mut BytegramDict<B, N>,
+}
+
+impl<'a, B, const N: usize> DictEditor<'a, B, N> {
+    #[inline]
+    pub(super) fn new(bytegram_dict: &'a mut BytegramDict<B, N>) -> Self {
+        Self { bytegram_dict }
+    }
+}
+
+impl<'a, B, const N: usize> IndexDictionaryEditor<Bytegram<N>> for DictEditor<'a, B, N>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn announce_new_terms(&mut self, terms: usize, term_size: usize) -> Result<()> {
+        let mut map = self.bytegram_dict.map_mut();
+        map.grow_to(map.len() + terms)?;
+        map.reserve_storage(terms, term_size * terms)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn insert_or_get_single(&mut self, term: &Bytegram<N>) -> Result<u32> {
+        let id = {
+            let mut map = self.bytegram_dict.map_mut();
+            if let Some(id) = map.get(term) {
+                return Ok(id);
+            }
+            let id = map.len();
+            map.insert(term, &(id as u32))?
+        };
+        // Keep the bytegrams in the order of their IDs for reverse lookups.
+        self.bytegram_dict
+            .terms_mut()
+            .extend(std::slice::from_ref(term));
+        Ok(id)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::edit::NewItem;
+use crate::index::dict::bytegram::bytegram::Bytegram;
+use crate::index::dict::ngram::generator::ShortStrings;
+use std::collections::HashSet;
+
+/// Splits strings into their overlapping `Bytegram<N>` windows of UTF-8 bytes. The same generator
+/// should be used for inserting and querying so both produce the same bytegrams.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BytegramGenerator<const N: usize> {
+    start_padding: Option<u8>,
+    end_padding: Option<u8>,
+    case_folding: bool,
+    short_strings: ShortStrings<u8>,
+}
+
+impl<const N: usize> BytegramGenerator<N> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepends N-1 `padding` bytes to the strings so the first bytes of a string also start
+    /// their own bytegrams.
+    pub fn with_start_padding(mut self, padding: u8) -> Self {
+        self.start_padding = Some(padding);
+        self
+    }
+
+    /// Appends N-1 `padding` bytes to the strings so the last bytes of a string also start their
+    /// own bytegrams.
+    pub fn with_end_padding(mut self, padding: u8) -> Self {
+        self.end_padding = Some(padding);
+        self
+    }
+
+    /// Lowercases all strings before splitting them.
+    pub fn with_case_folding(mut self) -> Self {
+        self.case_folding = true;
+        self
+    }
+
+    /// Sets how strings that are shorter than N bytes are handled.
+    pub fn with_short_strings(mut self, short_strings: ShortStrings<u8>) -> Self {
+        self.short_strings = short_strings;
+        self
+    }
+
+    /// Returns all bytegrams of `text` in the order they appear in. The same bytegram can be
+    /// returned multiple times.
+    pub fn generate(&self, text: &str) -> Vec<Bytegram<N>> {
+        if N == 0 {
+            return vec![];
+        }
+
+        let bytes = self.prepare(text);
+        if bytes.len() < N {
+            return match self.short_strings {
+                ShortStrings::Skip => vec![],
+                ShortStrings::Fill(fill) => {
+                    let mut bytegram = [fill; N];
+                    bytegram[..bytes.len()].copy_from_slice(&bytes);
+                    vec![Bytegram::from(bytegram)]
+                }
+            };
+        }
+
+        bytes
+            .windows(N)
+            .map(|w| Bytegram::try_from(w).unwrap())
+            .collect()
+    }
+
+    /// Returns all unique bytegrams of `text` in the order of their first occurrence.
+    pub fn generate_unique(&self, text: &str) -> Vec<Bytegram<N>> {
+        let mut seen = HashSet::new();
+        let mut bytegrams = self.generate(text);
+        bytegrams.retain(|i| seen.insert(*i));
+        bytegrams
+    }
+
+    /// Returns a new insertion item with all unique bytegrams of `text` as terms.
+    #[inline]
+    pub fn new_item<S>(&self, text: &str, store_item: S) -> NewItem<Bytegram<N>, S> {
+        NewItem::new(self.generate_unique(text), store_item)
+    }
+
+    /// Returns the padded and case folded bytes of `text`.
+    fn prepare(&self, text: &str) -> Vec<u8> {
+        let pad_len = N.saturating_sub(1);
+        let mut bytes = Vec::with_capacity(text.len() + pad_len * 2);
+
+        if let Some(pad) = self.start_padding {
+            bytes.extend(std::iter::repeat(pad).take(pad_len));
+        }
+
+        if self.case_folding {
+            bytes.extend(text.to_lowercase().bytes());
+        } else {
+            bytes.extend(text.bytes());
+        }
+
+        if let Some(pad) = self.end_padding {
+            bytes.extend(std::iter::repeat(pad).take(pad_len));
+        }
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bytegrams<const N: usize>(strs: &[&str]) -> Vec<Bytegram<N>> {
+        strs.iter()
+            .map(|i| Bytegram::try_from(*i).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generate() {
+        let generator = BytegramGenerator::<3>::new();
+        assert_eq!(
+            generator.generate("kanji"),
+            bytegrams(&["kan", "anj", "nji"])
+        );
+        assert!(generator.generate("ka").is_empty());
+
+        let generator = BytegramGenerator::<2>::new()
+            .with_start_padding(b'^')
+            .with_end_padding(b'$')
+            .with_case_folding();
+        assert_eq!(
+            generator.generate("AbC"),
+            bytegrams(&["^a", "ab", "bc", "c$"])
+        );
+
+        let generator = BytegramGenerator::<3>::new().with_short_strings(ShortStrings::Fill(b'_'));
+        assert_eq!(generator.generate("a"), bytegrams(&["a__"]));
+        assert_eq!(generator.generate_unique("aaaa"), bytegrams(&["aaa"]));
+    }
+}
@@ -0,0 +1,193 @@
+pub mod editor;
+pub mod generator;
+
+#[allow(clippy::module_inception)]
+pub mod bytegram;
+
+use crate::index::dict::bytegram::bytegram::Bytegram;
+use crate::index::dict::bytegram::editor::DictEditor;
+use crate::index::dict::EditableIndexDictionary;
+use crate::index::dict::IndexDictionary;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::map::FMap;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+
+/// Entry of the map from bytegrams to their IDs.
+const MAP_INDEX: usize = 0;
+/// Entry of the bytegrams ordered by their IDs.
+const TERMS_INDEX: usize = 1;
+
+/// An index Dictionary for bytegrams with a fixed amount of bytes.
+pub struct BytegramDict<B, const N: usize> {
+    backend: MultiFile<B>,
+}
+
+impl<B, const N: usize> BytegramDict<B, N>
+where
+    B: Backend,
+{
+    /// Returns the map from bytegrams to their IDs.
+    #[inline]
+    pub fn map(&self) -> FMap<GeneralSubBackend, Bytegram<N>, u32> {
+        self.backend.get_backend(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    fn terms(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, const N: usize> BytegramDict<B, N>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn map_mut(&mut self) -> FMap<MFileEntryMut<B>, Bytegram<N>, u32> {
+        self.backend.get_backend_mut(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn terms_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, const N: usize> IndexDictionary<Bytegram<N>> for BytegramDict<B, N>
+where
+    B: Backend,
+{
+    #[inline]
+    fn term_id(&self, term: &Bytegram<N>) -> Option<u32> {
+        self.map().get(term)
+    }
+
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<Bytegram<N>> {
+        self.terms().get_t(id as usize).ok()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.map().len()
+    }
+}
+
+impl<B, const N: usize> Creatable<B> for BytegramDict<B, N>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<FMap<_, Bytegram<N>, u32>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B, const N: usize> Initiable<B> for BytegramDict<B, N>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B, const N: usize> EditableIndexDictionary<Bytegram<N>> for BytegramDict<B, N>
+where
+    B: GrowableBackend,
+{
+    type Editor<'a> = DictEditor<'a, B, N> where Self: 'a;
+
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        DictEditor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::bytegram::generator::BytegramGenerator;
+    use crate::index::dict::ngram::ngram::Ngram;
+    use crate::index::dict::ngram::NGramDict;
+    use crate::index::dict::IndexDictionaryEditor;
+    use crate::index::preset::CompressedBytegramIndex;
+    use crate::retrieve::build::RetrieverBuilder;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+
+    #[test]
+    fn bytegram_dict() {
+        let mut bytegram_backend = MemoryBackend::create(MemoryData::new(vec![0u8; 8])).unwrap();
+        let mut ngram_backend = MemoryBackend::create(MemoryData::new(vec![0u8; 8])).unwrap();
+
+        let mut bytegram_dict: BytegramDict<_, 3> =
+            BytegramDict::create(&mut bytegram_backend).unwrap();
+        let mut ngram_dict: NGramDict<_, 3> = NGramDict::create(&mut ngram_backend).unwrap();
+
+        let mut bytegram_editor = bytegram_dict.editor();
+        let mut ngram_editor = ngram_dict.editor();
+        for i in 0..1000 {
+            let term = format!("{i:0>3}");
+
+            bytegram_editor
+                .insert_or_get_single(&Bytegram::try_from(&term).unwrap())
+                .unwrap();
+            ngram_editor
+                .insert_or_get_single(&Ngram::try_from(&term).unwrap())
+                .unwrap();
+        }
+
+        let bytegram_len = bytegram_dict.map().backend.get(2).unwrap().len();
+        let ngram_len = ngram_dict.layout.map_data_len();
+        assert!(bytegram_len < ngram_len);
+
+        for (bytegram, id) in bytegram_dict.iter() {
+            assert_eq!(bytegram_dict.term_id(&bytegram), Some(id));
+        }
+        let term = Bytegram::try_from("042").unwrap();
+        let id = bytegram_dict.term_id(&term).unwrap();
+        assert_eq!(bytegram_dict.term_by_id(id), Some(term));
+        assert_eq!(bytegram_dict.iter().count(), 1000);
+    }
+
+    #[test]
+    fn bytegram_index() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: CompressedBytegramIndex<_, u64, 3> =
+            CompressedBytegramIndex::create(backend).unwrap();
+        let generator = BytegramGenerator::<3>::new().with_case_folding();
+
+        let texts = ["Jotoba", "Kanji", "Kana", "Radicals", "kanji radicals"];
+        let mut editor = index.editor();
+        for (i, text) in texts.iter().enumerate() {
+            editor.insert(generator.new_item(text, i as u64)).unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let count = |query: &str| {
+            let mut retrieve_builder = RetrieverBuilder::new(&index);
+            retrieve_builder.add_terms(&generator.generate_unique(query));
+            retrieve_builder.count_intersection()
+        };
+
+        assert_eq!(count("kanji"), 2);
+        assert_eq!(count("KAN"), 3);
+        assert_eq!(count("radical"), 2);
+        assert_eq!(count("toba"), 1);
+        assert_eq!(count("xyz"), 0);
+    }
+}
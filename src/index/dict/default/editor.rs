@@ -2,7 +2,6 @@ use crate::index::dict::default::DefaultDict;
 use crate::index::dict::IndexDictionaryEditor;
 use crate::Result;
 use bytestore::backend::growable::GrowableBackend;
-use bytestore::backend::Backend;
 use bytestore::components::map::hashing;
 use bytestore::traits::deser::Deser;
 use std::cmp::Ordering;
@@ -21,19 +20,16 @@ impl<'a, B, T> DictEditor<'a, B, T> {
 
 impl<'a, B, T> DictEditor<'a, B, T>
 where
-    B: Backend,
+    B: GrowableBackend,
     T: Deser + Ord + hashing::Hash,
 {
     /// Improves lookup time for items returning a higher ordering than other items.
     #[inline]
-    pub fn optimize<C>(&mut self, mut cmp: C) -> Result<()>
+    pub fn optimize<C>(&mut self, cmp: C) -> Result<()>
     where
         C: FnMut(&T, &T) -> Ordering,
     {
-        self.dict
-            .map
-            .rehash_with_relevance(|a, b| cmp(a.key(), b.key()))?;
-        Ok(())
+        self.dict.layout.rehash_with_relevance(cmp)
     }
 
     /// Improves lookup time for terms with higher frequency.
@@ -53,18 +49,11 @@ where
 {
     #[inline]
     fn announce_new_terms(&mut self, terms: usize, term_size: usize) -> Result<()> {
-        let map = &mut self.dict.map;
-        map.grow_to(map.len() + terms)?;
-        map.reserve_storage(terms, term_size * terms)?;
-        Ok(())
+        self.dict.layout.reserve(terms, term_size)
     }
 
     #[inline]
     fn insert_or_get_single(&mut self, term: &T) -> Result<u32> {
-        if let Some(k) = self.dict.map.get(term) {
-            return Ok(k);
-        }
-        let id = self.dict.map.len();
-        Ok(self.dict.map.insert(term, &(id as u32))?)
+        self.dict.layout.insert_or_get(term)
     }
 }
@@ -1,19 +1,22 @@
 mod editor;
 
-use crate::index::dict::{EditableIndexDictionary, IndexDictionary};
-use editor::DictEditor;
+use crate::index::dict::layout::MapLayout;
+use crate::index::dict::{fuzzy, EditableIndexDictionary, FuzzyDictionary, IndexDictionary};
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
-use bytestore::components::map::{hashing, FMap};
+use bytestore::components::map::hashing;
 use bytestore::traits::creatable::Creatable;
 use bytestore::traits::deser::Deser;
 use bytestore::traits::initiable::Initiable;
-use std::marker::PhantomData;
+use editor::DictEditor;
 
-/// The default Dictionary implementation that can be used in most cases.
+/// The default Dictionary implementation that can be used in most cases. Next to the map from
+/// terms to their IDs, all terms are stored in the order of their IDs to allow reverse lookups.
+///
+/// Dictionaries created before reverse lookups existed can still be opened. Looking up their
+/// terms by ID has to scan all terms though.
 pub struct DefaultDict<B, T> {
-    pub map: FMap<B, T, u32>,
-    _p1: PhantomData<T>,
+    pub(crate) layout: MapLayout<B, T>,
 }
 
 impl<B, T> Initiable<B> for DefaultDict<B, T>
@@ -21,11 +24,8 @@ where
     B: Backend,
 {
     fn init(backend: B) -> bytestore::Result<Self> {
-        let map = FMap::init(backend)?;
-        Ok(Self {
-            map,
-            _p1: PhantomData,
-        })
+        let layout = MapLayout::init(backend)?;
+        Ok(Self { layout })
     }
 }
 
@@ -34,11 +34,20 @@ where
     B: GrowableBackend,
 {
     fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
-        let map = FMap::with_capacity(backend, capacity)?;
-        Ok(Self {
-            map,
-            _p1: PhantomData,
-        })
+        let layout = MapLayout::with_capacity(backend, capacity)?;
+        Ok(Self { layout })
+    }
+}
+
+impl<B, T> DefaultDict<B, T>
+where
+    B: GrowableBackend,
+    T: hashing::Hash + Eq + Deser,
+{
+    /// Writes all changes to the backend.
+    #[inline]
+    pub fn flush(&mut self) -> crate::Result<()> {
+        self.layout.flush()
     }
 }
 
@@ -49,12 +58,40 @@ where
 {
     #[inline]
     fn term_id(&self, term: &T) -> Option<u32> {
-        self.map.get(term)
+        self.layout.get(term)
+    }
+
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<T> {
+        self.layout.term_by_id(id)
     }
 
     #[inline]
     fn len(&self) -> usize {
-        self.map.len()
+        self.layout.len()
+    }
+}
+
+impl<B> FuzzyDictionary for DefaultDict<B, String>
+where
+    B: Backend,
+{
+    /// Terms aren't ordered so this has to compare the query with every term in the dictionary.
+    fn fuzzy_term_ids(
+        &self,
+        term: &str,
+        max_distance: u32,
+        prefix_len: usize,
+        limit: usize,
+    ) -> Vec<(u32, u32)> {
+        let prefix = fuzzy::prefix(term, prefix_len);
+        let matches = self.layout.filter_map(|(t, id)| {
+            if !t.starts_with(prefix) {
+                return None;
+            }
+            Some((id, fuzzy::distance(term, &t, max_distance)?))
+        });
+        fuzzy::closest(matches, limit)
     }
 }
 
@@ -93,10 +130,21 @@ mod test {
 
         for (term, id) in terms.iter().zip(ids.iter()) {
             assert_eq!(dict.term_id(term), Some(*id));
+            assert_eq!(dict.term_by_id(*id).as_ref(), Some(term));
         }
+        assert_eq!(dict.term_by_id(terms.len() as u32), None);
+
+        let iterated: Vec<_> = dict.iter().map(|i| i.0).collect();
+        assert_eq!(iterated, terms);
+
+        // The exact match, 27 substitutions and 2 deletions.
+        let res = dict.fuzzy_term_ids("9991", 1, 1, 100);
+        assert_eq!(res.len(), 30);
+        assert_eq!(res[0], (dict.term_id(&"9991".to_string()).unwrap(), 0));
+        assert_eq!(dict.fuzzy_term_ids("9991", 1, 1, 5).len(), 5);
 
         let start = Instant::now();
-        dict.map.flush().unwrap();
+        dict.flush().unwrap();
         println!("flushed {} terms in {:?}", terms.len(), start.elapsed());
     }
 }
@@ -0,0 +1,95 @@
+use crate::index::dict::fst_dict::{FstDict, LEN_SIZE};
+use crate::index::dict::{IndexDictionary, IndexDictionaryEditor};
+use crate::index::entry::overwrite_entries;
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::traits::deser::Deser;
+use fst::{MapBuilder, Streamer};
+use std::collections::BTreeMap;
+
+/// Edits FstDicts. Inserted terms are buffered and get written into the dictionary together with
+/// the rebuilt FST on `flush()`. Terms of an editor that gets dropped without flushing are lost.
+pub struct FstDictEditor<'a, B, T> {
+    dict: &'a mut FstDict<B, T>,
+
+    /// Terms inserted since the last flush mapped to their IDs.
+    pending: BTreeMap<Vec<u8>, u32>,
+    /// Terms inserted since the last flush ordered by their IDs.
+    new_terms: Vec<T>,
+}
+
+impl<'a, B, T> FstDictEditor<'a, B, T> {
+    #[inline]
+    pub(crate) fn new(dict: &'a mut FstDict<B, T>) -> Self {
+        Self {
+            dict,
+            pending: BTreeMap::new(),
+            new_terms: vec![],
+        }
+    }
+}
+
+impl<'a, B, T> FstDictEditor<'a, B, T>
+where
+    B: GrowableBackend,
+{
+    /// Builds a new FST containing the existing and all pending terms.
+    fn build_fst(&self) -> Result<Vec<u8>> {
+        let mut builder = MapBuilder::memory();
+        let mut pending = self.pending.iter().peekable();
+
+        if let Some(fst) = self.dict.fst() {
+            let mut stream = fst.stream();
+            while let Some((term, id)) = stream.next() {
+                while let Some((new, new_id)) = pending.next_if(|i| i.0.as_slice() < term) {
+                    builder.insert(new, *new_id as u64)?;
+                }
+                builder.insert(term, id)?;
+            }
+        }
+
+        for (new, new_id) in pending {
+            builder.insert(new, *new_id as u64)?;
+        }
+
+        Ok(builder.into_inner()?)
+    }
+}
+
+impl<'a, B, T> IndexDictionaryEditor<T> for FstDictEditor<'a, B, T>
+where
+    B: GrowableBackend,
+    T: Deser + AsRef<[u8]> + Clone,
+{
+    fn insert_or_get_single(&mut self, term: &T) -> Result<u32> {
+        if let Some(id) = self.dict.term_id(term) {
+            return Ok(id);
+        }
+        if let Some(id) = self.pending.get(term.as_ref()) {
+            return Ok(*id);
+        }
+
+        let id = (self.dict.len() + self.new_terms.len()) as u32;
+        self.pending.insert(term.as_ref().to_vec(), id);
+        self.new_terms.push(term.clone());
+        Ok(id)
+    }
+
+    /// Writes all pending terms and rebuilds the FST with them.
+    fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let fst = self.build_fst()?;
+        let mut data = Vec::with_capacity(LEN_SIZE + fst.len());
+        data.extend_from_slice(&(fst.len() as u64).to_le_bytes());
+        data.extend(fst);
+        self.dict.terms_mut().extend(&self.new_terms);
+        overwrite_entries(&mut self.dict.fst_file_mut(), vec![(0, data)])?;
+
+        self.pending.clear();
+        self.new_terms.clear();
+        Ok(())
+    }
+}
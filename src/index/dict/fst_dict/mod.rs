@@ -0,0 +1,400 @@
+pub mod editor;
+
+use crate::index::dict::pattern::TermPattern;
+use crate::index::dict::{
+    fuzzy, EditableIndexDictionary, FuzzyDictionary, IndexDictionary, PatternDictionary,
+    PrefixDictionary, RangeDictionary,
+};
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
+use bytestore::traits::initiable::Initiable;
+use editor::FstDictEditor;
+use fst::automaton::{Automaton, Levenshtein, Str};
+use fst::{IntoStreamer, Map, Streamer};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+/// Entry holding the serialized FST.
+const FST_INDEX: usize = 0;
+/// Entry of the terms ordered by their IDs.
+const TERMS_INDEX: usize = 1;
+
+/// Size of the length header in front of the serialized FST.
+const LEN_SIZE: usize = 8;
+
+/// A compact dictionary storing its terms in a finite state transducer which maps the terms bytes
+/// to their IDs. The FST is read directly from the backend without copying or deserializing it
+/// and keeps the terms ordered, which allows prefix, range and automaton queries.
+///
+/// An FST can't be updated in place, so new terms are only visible after the editor has been
+/// flushed, which rebuilds the FST. Terms should therefore be inserted in large batches.
+pub struct FstDict<B, T> {
+    backend: MultiFile<B>,
+    _p1: PhantomData<T>,
+}
+
+impl<B, T> Creatable<B> for FstDict<B, T>
+where
+    B: GrowableBackend,
+{
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, T> Initiable<B> for FstDict<B, T>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, T> FstDict<B, T>
+where
+    B: Backend,
+{
+    /// Returns the FST mapping all terms to their IDs or `None` if no terms have been flushed yet
+    /// or the stored FST is invalid. The FST is read from the backend without copying it.
+    pub fn fst(&self) -> Option<Map<&[u8]>> {
+        let ifile: IndexedFile<_> = self.backend.get_backend(FST_INDEX)?;
+        let data = ifile.get(0).ok()?;
+        if data.len() < LEN_SIZE {
+            return None;
+        }
+        let len = u64::from_le_bytes(data[..LEN_SIZE].try_into().unwrap()) as usize;
+        let data = data.get(LEN_SIZE..LEN_SIZE.checked_add(len)?)?;
+
+        // Safety:
+        // The actual lifetime of the data is bound to <B> which &self is also bound to.
+        let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+        Map::new(data).ok()
+    }
+
+    /// Returns all terms ordered by their IDs.
+    #[inline]
+    fn terms(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, T> FstDict<B, T>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn fst_file_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(FST_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn terms_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, T> IndexDictionary<T> for FstDict<B, T>
+where
+    B: Backend,
+    T: Deser + AsRef<[u8]>,
+{
+    #[inline]
+    fn term_id(&self, term: &T) -> Option<u32> {
+        self.fst()?.get(term).map(|i| i as u32)
+    }
+
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<T> {
+        self.terms().get_t(id as usize).ok()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.terms().count()
+    }
+}
+
+impl<B, T> PrefixDictionary for FstDict<B, T>
+where
+    B: Backend,
+{
+    fn prefix_term_ids(&self, prefix: &str, limit: usize) -> Vec<u32> {
+        let Some(fst) = self.fst() else {
+            return vec![];
+        };
+
+        let mut stream = fst.search(Str::new(prefix).starts_with()).into_stream();
+        let mut out = vec![];
+        while out.len() < limit {
+            let Some((_, id)) = stream.next() else {
+                break;
+            };
+            out.push(id as u32);
+        }
+        out
+    }
+}
+
+impl<B, T> RangeDictionary<T> for FstDict<B, T>
+where
+    B: Backend,
+    T: AsRef<[u8]>,
+{
+    /// Terms are ordered by their bytes.
+    fn range_term_ids(&self, start: Bound<&T>, end: Bound<&T>, limit: usize) -> Vec<u32> {
+        let Some(fst) = self.fst() else {
+            return vec![];
+        };
+
+        let mut range = fst.range();
+        range = match start {
+            Bound::Included(s) => range.ge(s),
+            Bound::Excluded(s) => range.gt(s),
+            Bound::Unbounded => range,
+        };
+        range = match end {
+            Bound::Included(e) => range.le(e),
+            Bound::Excluded(e) => range.lt(e),
+            Bound::Unbounded => range,
+        };
+
+        let mut stream = range.into_stream();
+        let mut out = vec![];
+        while out.len() < limit {
+            let Some((_, id)) = stream.next() else {
+                break;
+            };
+            out.push(id as u32);
+        }
+        out
+    }
+}
+
+impl<B, T> PatternDictionary for FstDict<B, T>
+where
+    B: Backend,
+{
+    fn pattern_term_ids(&self, pattern: &TermPattern, limit: usize) -> Vec<u32> {
+        let Some(fst) = self.fst() else {
+            return vec![];
+        };
+
+        let mut stream = fst.search(pattern).into_stream();
+        let mut out = vec![];
+        while out.len() < limit {
+            let Some((_, id)) = stream.next() else {
+                break;
+            };
+            out.push(id as u32);
+        }
+        out
+    }
+}
+
+impl<B, T> FuzzyDictionary for FstDict<B, T>
+where
+    B: Backend,
+{
+    fn fuzzy_term_ids(
+        &self,
+        term: &str,
+        max_distance: u32,
+        prefix_len: usize,
+        limit: usize,
+    ) -> Vec<(u32, u32)> {
+        let Some(fst) = self.fst() else {
+            return vec![];
+        };
+
+        let prefix = Str::new(fuzzy::prefix(term, prefix_len)).starts_with();
+        match Levenshtein::new(term, max_distance) {
+            Ok(lev) => fuzzy_search(&fst, lev.intersection(prefix), term, max_distance, limit),
+            // Building the automaton fails for too many states. Compare all prefixed terms then.
+            Err(_) => fuzzy_search(&fst, prefix, term, max_distance, limit),
+        }
+    }
+}
+
+/// Returns the closest terms matching `aut` that are within the given distance of `term`.
+fn fuzzy_search<A: Automaton>(
+    fst: &Map<&[u8]>,
+    aut: A,
+    term: &str,
+    max_distance: u32,
+    limit: usize,
+) -> Vec<(u32, u32)> {
+    let mut stream = fst.search(aut).into_stream();
+    let mut matches = vec![];
+    while let Some((key, id)) = stream.next() {
+        let Ok(key) = std::str::from_utf8(key) else {
+            continue;
+        };
+        if let Some(dist) = fuzzy::distance(term, key, max_distance) {
+            matches.push((id as u32, dist));
+        }
+    }
+    fuzzy::closest(matches, limit)
+}
+
+impl<B, T> EditableIndexDictionary<T> for FstDict<B, T>
+where
+    B: GrowableBackend,
+    T: Deser + AsRef<[u8]> + Clone,
+{
+    type Editor<'a> = FstDictEditor<'a, B, T> where T: 'a, B: 'a, Self: 'a;
+
+    /// Returns an editor for the dictionary.
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        FstDictEditor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::IndexDictionaryEditor;
+    use crate::index::entry::overwrite_entries;
+    use crate::index::preset::FstIndex;
+    use crate::index::test::{fill_index, index_test_data};
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn fst_dict() {
+        let mut dict: FstDict<_, String> = FstDict::create_mem_with_capacity(10).unwrap();
+        assert_eq!(dict.term_id(&"a".to_string()), None);
+
+        let terms: Vec<_> = (0..1000).rev().map(|i| format!("{i}")).collect();
+        let (first, second) = terms.split_at(500);
+
+        let mut editor = dict.editor();
+        let mut ids = editor.insert_or_get(first).unwrap();
+        // Terms are visible to the editor before flushing.
+        assert_eq!(editor.insert_or_get_single(&first[3]).unwrap(), ids[3]);
+        editor.flush().unwrap();
+        assert_eq!(dict.term_id(&first[3]), Some(ids[3]));
+
+        // Flushing again merges new terms into the existing FST.
+        let mut editor = dict.editor();
+        ids.extend(editor.insert_or_get(second).unwrap());
+        editor.flush().unwrap();
+
+        assert_eq!(dict.len(), terms.len());
+        for (term, id) in terms.iter().zip(ids.iter()) {
+            assert_eq!(dict.term_id(term), Some(*id));
+            assert_eq!(dict.term_by_id(*id).as_ref(), Some(term));
+        }
+        assert_eq!(dict.term_id(&"abc".to_string()), None);
+
+        // Terms of editors that haven't been flushed don't get written.
+        let mut editor = dict.editor();
+        editor.insert_or_get_single(&"abc".to_string()).unwrap();
+        drop(editor);
+        assert_eq!(dict.len(), terms.len());
+        assert_eq!(dict.term_by_id(terms.len() as u32), None);
+
+        let prefixed: Vec<_> = dict
+            .prefix_term_ids("99", 100)
+            .into_iter()
+            .map(|id| dict.term_by_id(id).unwrap())
+            .collect();
+        assert_eq!(
+            prefixed,
+            ["99", "990", "991", "992", "993", "994", "995", "996", "997", "998", "999"]
+        );
+        assert_eq!(dict.prefix_term_ids("99", 2).len(), 2);
+
+        let res = dict.fuzzy_term_ids("9991", 1, 1, 100);
+        let mut exp: Vec<_> = ["999", "991"]
+            .iter()
+            .map(|i| (dict.term_id(&i.to_string()).unwrap(), 1))
+            .collect();
+        exp.sort_unstable();
+        assert_eq!(res, exp);
+        assert_eq!(dict.fuzzy_term_ids("9991", 1, 1, 1).len(), 1);
+
+        let matched: Vec<_> = dict
+            .pattern_term_ids(&TermPattern::wildcard("9*1").unwrap(), 100)
+            .into_iter()
+            .map(|id| dict.term_by_id(id).unwrap())
+            .collect();
+        assert_eq!(
+            matched,
+            ["901", "91", "911", "921", "931", "941", "951", "961", "971", "981", "991"]
+        );
+        let ranged: Vec<_> = dict
+            .range_term_ids(
+                Bound::Excluded(&"997".to_string()),
+                Bound::Included(&"9990".to_string()),
+                100,
+            )
+            .into_iter()
+            .map(|id| dict.term_by_id(id).unwrap())
+            .collect();
+        assert_eq!(ranged, ["998", "999"]);
+
+        let pattern = TermPattern::regex("1[0-9]?").unwrap();
+        assert_eq!(dict.pattern_term_ids(&pattern, 100).len(), 11);
+        assert_eq!(dict.pattern_term_ids(&pattern, 3).len(), 3);
+    }
+
+    #[test]
+    fn invalid_fst() {
+        let mut dict: FstDict<_, String> = FstDict::create_mem_with_capacity(10).unwrap();
+        let mut editor = dict.editor();
+        editor.insert_or_get_single(&"a".to_string()).unwrap();
+        editor.flush().unwrap();
+        assert_eq!(dict.term_id(&"a".to_string()), Some(0));
+
+        // Length prefix pointing past the end of the entry.
+        let mut data = 100u64.to_le_bytes().to_vec();
+        data.extend([0; 4]);
+        overwrite_entries(&mut dict.fst_file_mut(), vec![(0, data)]).unwrap();
+        assert!(dict.fst().is_none());
+        assert_eq!(dict.term_id(&"a".to_string()), None);
+
+        // Valid length but no valid FST.
+        let mut data = 4u64.to_le_bytes().to_vec();
+        data.extend([0xFF; 4]);
+        overwrite_entries(&mut dict.fst_file_mut(), vec![(0, data)]).unwrap();
+        assert!(dict.fst().is_none());
+        assert!(dict.prefix_term_ids("a", 10).is_empty());
+    }
+
+    #[test]
+    fn fst_index() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: FstIndex<_, String, String> = FstIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        // Committing flushes the dictionary.
+        fill_index(index.editor(), &data);
+
+        let dict = index.dict();
+        assert_eq!(dict.len(), exp_index.len());
+        for term in exp_index.keys() {
+            let id = dict.term_id(term).unwrap();
+            assert_eq!(dict.term_by_id(id).as_ref(), Some(term));
+        }
+    }
+}
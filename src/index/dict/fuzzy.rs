@@ -0,0 +1,77 @@
+/// Returns the Levenshtein distance between `a` and `b` counted in characters or `None` if it's
+/// larger than `max`.
+pub(crate) fn distance(a: &str, b: &str, max: u32) -> Option<u32> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max = max as usize;
+
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        let mut row_min = curr[0];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+            row_min = row_min.min(curr[j + 1]);
+        }
+        // Distances can't get smaller in later rows.
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let dist = prev[b.len()];
+    (dist <= max).then_some(dist as u32)
+}
+
+/// Returns the first `len` characters of `term`.
+#[inline]
+pub(crate) fn prefix(term: &str, len: usize) -> &str {
+    term.char_indices()
+        .nth(len)
+        .map(|(i, _)| &term[..i])
+        .unwrap_or(term)
+}
+
+/// Returns the `limit` matches with the smallest distance ordered by their distance.
+#[inline]
+pub(crate) fn closest<I>(matches: I, limit: usize) -> Vec<(u32, u32)>
+where
+    I: IntoIterator<Item = (u32, u32)>,
+{
+    let mut matches: Vec<_> = matches.into_iter().collect();
+    matches.sort_unstable_by_key(|(id, dist)| (*dist, *id));
+    matches.truncate(limit);
+    matches
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("kanji", "kanji", 0), Some(0));
+        assert_eq!(distance("kanij", "kanji", 2), Some(2));
+        assert_eq!(distance("kanij", "kanji", 1), None);
+        assert_eq!(distance("kana", "kanji", 2), Some(2));
+        assert_eq!(distance("", "abc", 3), Some(3));
+        assert_eq!(distance("かんじ", "かじ", 1), Some(1));
+        assert_eq!(distance("a", "abcdef", 2), None);
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(prefix("kanji", 2), "ka");
+        assert_eq!(prefix("kanji", 0), "");
+        assert_eq!(prefix("kanji", 10), "kanji");
+        assert_eq!(prefix("かんじ", 1), "か");
+    }
+}
@@ -0,0 +1,262 @@
+use crate::Result;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::map::{hashing, FMap};
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
+use bytestore::traits::initiable::Initiable;
+use std::cmp::Ordering;
+
+/// Entry of the map from terms to their IDs.
+const MAP_INDEX: usize = 0;
+/// Entry of the terms ordered by their IDs.
+const TERMS_INDEX: usize = 1;
+/// Entry holding [`FORMAT_VERSION`].
+const FORMAT_INDEX: usize = 2;
+
+/// Version of the layout that stores the terms next to the map.
+const FORMAT_VERSION: u32 = 1;
+
+/// Storage layout of dictionaries that map hashed terms to their IDs.
+///
+/// New dictionaries are a [`MultiFile`] holding the map, all terms in the order of their IDs and
+/// the format version. Dictionaries created before reverse lookups existed are a bare map. They
+/// are still opened and edited in place but looking up terms by their ID has to scan the map.
+pub(crate) enum MapLayout<B, K> {
+    Terms(MultiFile<B>),
+    Legacy(FMap<B, K, u32>),
+}
+
+impl<B, K> Initiable<B> for MapLayout<B, K>
+where
+    B: Backend,
+{
+    fn init(mut backend: B) -> bytestore::Result<Self> {
+        let has_terms = MultiFile::init(&mut backend).is_ok_and(|i| has_format(&i));
+        if has_terms {
+            return Ok(Self::Terms(MultiFile::init(backend)?));
+        }
+        Ok(Self::Legacy(FMap::init(backend)?))
+    }
+}
+
+impl<B, K> Creatable<B> for MapLayout<B, K>
+where
+    B: GrowableBackend,
+{
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<FMap<_, K, u32>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend
+            .get_backend_mut::<IndexedFile<_>>(FORMAT_INDEX)
+            .unwrap()
+            .extend(&[FORMAT_VERSION]);
+        Ok(Self::Terms(backend))
+    }
+}
+
+/// Returns `true` if `backend` holds the entries of the current layout.
+fn has_format<B: Backend>(backend: &MultiFile<B>) -> bool {
+    backend
+        .get_backend::<IndexedFile<_>>(FORMAT_INDEX)
+        .and_then(|i| i.get_t(0).ok())
+        == Some(FORMAT_VERSION)
+}
+
+impl<B, K> MapLayout<B, K>
+where
+    B: Backend,
+    K: hashing::Hash + Eq + Deser,
+{
+    #[inline]
+    fn map(backend: &MultiFile<B>) -> FMap<GeneralSubBackend, K, u32> {
+        backend.get_backend(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, term: &K) -> Option<u32> {
+        match self {
+            Self::Terms(backend) => Self::map(backend).get(term),
+            Self::Legacy(map) => map.get(term),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn term_by_id(&self, id: u32) -> Option<K> {
+        match self {
+            Self::Terms(backend) => backend
+                .get_backend::<IndexedFile<_>>(TERMS_INDEX)
+                .unwrap()
+                .get_t(id as usize)
+                .ok(),
+            Self::Legacy(map) => map.iter().find(|(_, i)| *i == id).map(|(t, _)| t),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Terms(backend) => Self::map(backend).len(),
+            Self::Legacy(map) => map.len(),
+        }
+    }
+
+    /// Calls `f` with every term and its ID in no particular order and collects the returned
+    /// values.
+    pub(crate) fn filter_map<F, R>(&self, f: F) -> Vec<R>
+    where
+        F: FnMut((K, u32)) -> Option<R>,
+    {
+        match self {
+            Self::Terms(backend) => Self::map(backend).iter().filter_map(f).collect(),
+            Self::Legacy(map) => map.iter().filter_map(f).collect(),
+        }
+    }
+
+    /// Returns the size of the map's data in bytes.
+    #[cfg(test)]
+    pub(crate) fn map_data_len(&self) -> usize {
+        match self {
+            Self::Terms(backend) => Self::map(backend).backend.get(2).unwrap().len(),
+            Self::Legacy(map) => map.backend.get(2).unwrap().len(),
+        }
+    }
+}
+
+impl<B, K> MapLayout<B, K>
+where
+    B: GrowableBackend,
+    K: hashing::Hash + Eq + Deser,
+{
+    #[inline]
+    pub(crate) fn reserve(&mut self, terms: usize, term_size: usize) -> Result<()> {
+        match self {
+            Self::Terms(backend) => reserve(
+                &mut backend.get_backend_mut(MAP_INDEX).unwrap(),
+                terms,
+                term_size,
+            ),
+            Self::Legacy(map) => reserve(map, terms, term_size),
+        }
+    }
+
+    /// Returns the ID of `term` and inserts it if it's not in the map yet.
+    #[inline]
+    pub(crate) fn insert_or_get(&mut self, term: &K) -> Result<u32> {
+        match self {
+            Self::Terms(backend) => {
+                let (id, new) =
+                    insert_or_get(&mut backend.get_backend_mut(MAP_INDEX).unwrap(), term)?;
+                // Keep the terms in the order of their IDs for reverse lookups.
+                if new {
+                    backend
+                        .get_backend_mut::<IndexedFile<_>>(TERMS_INDEX)
+                        .unwrap()
+                        .extend(std::slice::from_ref(term));
+                }
+                Ok(id)
+            }
+            Self::Legacy(map) => Ok(insert_or_get(map, term)?.0),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn rehash_with_relevance<C>(&mut self, mut cmp: C) -> Result<()>
+    where
+        C: FnMut(&K, &K) -> Ordering,
+    {
+        match self {
+            Self::Terms(backend) => {
+                let mut map: FMap<_, K, u32> = backend.get_backend_mut(MAP_INDEX).unwrap();
+                map.rehash_with_relevance(|a, b| cmp(a.key(), b.key()))?;
+            }
+            Self::Legacy(map) => map.rehash_with_relevance(|a, b| cmp(a.key(), b.key()))?,
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn flush(&mut self) -> Result<()> {
+        match self {
+            Self::Terms(backend) => backend.flush()?,
+            Self::Legacy(map) => map.flush()?,
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn reserve<B, K>(map: &mut FMap<B, K, u32>, terms: usize, term_size: usize) -> Result<()>
+where
+    B: GrowableBackend,
+    K: hashing::Hash + Eq + Deser,
+{
+    map.grow_to(map.len() + terms)?;
+    map.reserve_storage(terms, term_size * terms)?;
+    Ok(())
+}
+
+/// Returns the ID of `term` and whether it was newly inserted.
+#[inline]
+fn insert_or_get<B, K>(map: &mut FMap<B, K, u32>, term: &K) -> Result<(u32, bool)>
+where
+    B: GrowableBackend,
+    K: hashing::Hash + Eq + Deser,
+{
+    if let Some(id) = map.get(term) {
+        return Ok((id, false));
+    }
+    let id = map.len();
+    Ok((map.insert(term, &(id as u32))?, true))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::default::DefaultDict;
+    use crate::index::dict::{EditableIndexDictionary, IndexDictionary, IndexDictionaryEditor};
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+
+    #[test]
+    fn legacy_map() {
+        let mut backend = MemoryBackend::create(MemoryData::new(vec![0u8; 8])).unwrap();
+        let mut map: FMap<_, String, u32> = FMap::create(&mut backend).unwrap();
+        map.insert(&"a".to_string(), &0).unwrap();
+        map.insert(&"b".to_string(), &1).unwrap();
+        drop(map);
+
+        let mut dict: DefaultDict<_, String> = DefaultDict::init(&mut backend).unwrap();
+        assert!(matches!(dict.layout, MapLayout::Legacy(_)));
+        assert_eq!(dict.term_id(&"a".to_string()), Some(0));
+        assert_eq!(dict.term_by_id(1), Some("b".to_string()));
+
+        let id = dict
+            .editor()
+            .insert_or_get_single(&"c".to_string())
+            .unwrap();
+        assert_eq!(id, 2);
+        assert_eq!(dict.term_by_id(id), Some("c".to_string()));
+        assert_eq!(dict.len(), 3);
+    }
+
+    #[test]
+    fn current_layout() {
+        let mut backend = MemoryBackend::create(MemoryData::new(vec![0u8; 8])).unwrap();
+        let mut dict: DefaultDict<_, String> = DefaultDict::create(&mut backend).unwrap();
+        dict.editor()
+            .insert_or_get_single(&"a".to_string())
+            .unwrap();
+        drop(dict);
+
+        let dict: DefaultDict<_, String> = DefaultDict::init(&mut backend).unwrap();
+        assert!(matches!(dict.layout, MapLayout::Terms(_)));
+        assert_eq!(dict.term_by_id(0), Some("a".to_string()));
+    }
+}
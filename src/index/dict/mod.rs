@@ -1,7 +1,16 @@
+pub mod bytegram;
 pub mod default;
+pub mod fst_dict;
+pub(crate) mod fuzzy;
+pub(crate) mod layout;
 pub mod ngram;
+pub mod pattern;
+pub mod sorted;
 
 use crate::Result;
+use pattern::TermPattern;
+use std::marker::PhantomData;
+use std::ops::Bound;
 
 pub trait IndexDictionary<T> {
     /// Returns the unique ID of the given term.
@@ -13,6 +22,18 @@ pub trait IndexDictionary<T> {
         self.term_id(term).is_some()
     }
 
+    /// Returns the term with the given ID.
+    fn term_by_id(&self, id: u32) -> Option<T>;
+
+    /// Returns an iterator over all terms and their IDs ordered by ID.
+    #[inline]
+    fn iter(&self) -> TermIter<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        TermIter::new(self)
+    }
+
     /// Returns the amount of terms in the dictionary.
     fn len(&self) -> usize;
 
@@ -22,6 +43,90 @@ pub trait IndexDictionary<T> {
     }
 }
 
+/// Dictionaries that can expand a prefix to all terms starting with it.
+pub trait PrefixDictionary {
+    /// Returns the IDs of all terms starting with `prefix` in the order of the terms. At most
+    /// `limit` IDs are returned.
+    fn prefix_term_ids(&self, prefix: &str, limit: usize) -> Vec<u32>;
+}
+
+/// Dictionaries that keep their terms ordered and can return all terms within a range.
+pub trait RangeDictionary<T> {
+    /// Returns the IDs of all terms between `start` and `end` in the order of the terms. At most
+    /// `limit` IDs are returned.
+    fn range_term_ids(&self, start: Bound<&T>, end: Bound<&T>, limit: usize) -> Vec<u32>;
+}
+
+/// Dictionaries that can expand a term pattern to all terms matching it.
+pub trait PatternDictionary {
+    /// Returns the IDs of all terms matching `pattern` in the order of the terms. At most `limit`
+    /// IDs are returned.
+    fn pattern_term_ids(&self, pattern: &TermPattern, limit: usize) -> Vec<u32>;
+}
+
+/// Dictionaries that can expand a term to all similar terms.
+pub trait FuzzyDictionary {
+    /// Returns the IDs of all terms within the Levenshtein distance `max_distance` of `term`
+    /// together with their distance. The first `prefix_len` characters of the terms have to match
+    /// `term` exactly. At most `limit` terms with the smallest distance are returned, ordered by
+    /// their distance.
+    fn fuzzy_term_ids(
+        &self,
+        term: &str,
+        max_distance: u32,
+        prefix_len: usize,
+        limit: usize,
+    ) -> Vec<(u32, u32)>;
+}
+
+/// Iterator over all terms of a dictionary and their IDs.
+pub struct TermIter<'a, D, T> {
+    dict: &'a D,
+    pos: u32,
+    len: u32,
+    _p1: PhantomData<T>,
+}
+
+impl<'a, D, T> TermIter<'a, D, T>
+where
+    D: IndexDictionary<T>,
+{
+    #[inline]
+    fn new(dict: &'a D) -> Self {
+        let len = dict.len().try_into().expect("Too many terms");
+        Self {
+            dict,
+            pos: 0,
+            len,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<'a, D, T> Iterator for TermIter<'a, D, T>
+where
+    D: IndexDictionary<T>,
+{
+    type Item = (T, u32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.len {
+            let id = self.pos;
+            self.pos += 1;
+            if let Some(term) = self.dict.term_by_id(id) {
+                return Some((term, id));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.len - self.pos) as usize))
+    }
+}
+
 pub trait EditableIndexDictionary<T>: IndexDictionary<T> {
     type Editor<'a>: IndexDictionaryEditor<T> + 'a
     where
@@ -39,6 +144,12 @@ pub trait IndexDictionaryEditor<T> {
     /// Should insert all non existent terms from `terms`. Returns the ID of the term.
     fn insert_or_get_single(&mut self, terms: &T) -> Result<u32>;
 
+    /// Writes pending changes into the dictionary. Dictionaries that can't be updated in place
+    /// buffer newly inserted terms until this is called.
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     /// Should insert all non existent terms from `terms`. Returns their IDs in the same order.
     fn insert_or_get(&mut self, terms: &[T]) -> Result<Vec<u32>> {
         let mut ids = Vec::with_capacity(terms.len());
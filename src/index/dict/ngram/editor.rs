@@ -21,15 +21,11 @@ where
 {
     #[inline]
     fn announce_new_terms(&mut self, terms: usize, term_size: usize) -> Result<()> {
-        let map = &mut self.ngram_dict.backend;
-        map.grow_to(map.len() + terms)?;
-        map.reserve_storage(terms, term_size * terms)?;
-        Ok(())
+        self.ngram_dict.layout.reserve(terms, term_size)
     }
 
     #[inline]
     fn insert_or_get_single(&mut self, term: &Ngram<N>) -> Result<u32> {
-        let id = self.ngram_dict.backend.len();
-        Ok(self.ngram_dict.backend.insert(term, &(id as u32))?)
+        self.ngram_dict.layout.insert_or_get(term)
     }
 }
@@ -0,0 +1,160 @@
+use crate::edit::NewItem;
+use crate::index::dict::ngram::ngram::Ngram;
+use std::collections::HashSet;
+
+/// How strings with less than N characters (after padding) are handled. `C` is the unit the
+/// generator splits strings into, `char` for ngrams and `u8` for bytegrams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShortStrings<C = char> {
+    /// No ngrams are generated.
+    #[default]
+    Skip,
+    /// The string is filled up to N units with the given one.
+    Fill(C),
+}
+
+/// Splits strings into their overlapping `Ngram<N>` windows. The same generator should be used
+/// for inserting and querying so both produce the same ngrams.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NgramGenerator<const N: usize> {
+    start_padding: Option<char>,
+    end_padding: Option<char>,
+    case_folding: bool,
+    short_strings: ShortStrings,
+}
+
+impl<const N: usize> NgramGenerator<N> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepends N-1 `padding` characters to the strings so the first characters of a string
+    /// also start their own ngrams.
+    pub fn with_start_padding(mut self, padding: char) -> Self {
+        self.start_padding = Some(padding);
+        self
+    }
+
+    /// Appends N-1 `padding` characters to the strings so the last characters of a string also
+    /// start their own ngrams.
+    pub fn with_end_padding(mut self, padding: char) -> Self {
+        self.end_padding = Some(padding);
+        self
+    }
+
+    /// Lowercases all strings before splitting them.
+    pub fn with_case_folding(mut self) -> Self {
+        self.case_folding = true;
+        self
+    }
+
+    /// Sets how strings that are shorter than N characters are handled.
+    pub fn with_short_strings(mut self, short_strings: ShortStrings) -> Self {
+        self.short_strings = short_strings;
+        self
+    }
+
+    /// Returns all ngrams of `text` in the order they appear in. The same ngram can be returned
+    /// multiple times.
+    pub fn generate(&self, text: &str) -> Vec<Ngram<N>> {
+        if N == 0 {
+            return vec![];
+        }
+
+        let chars = self.prepare(text);
+        if chars.len() < N {
+            return match self.short_strings {
+                ShortStrings::Skip => vec![],
+                ShortStrings::Fill(c) => {
+                    let mut ngram = [c; N];
+                    ngram[..chars.len()].copy_from_slice(&chars);
+                    vec![Ngram::from(ngram)]
+                }
+            };
+        }
+
+        chars
+            .windows(N)
+            .map(|w| Ngram::from(<[char; N]>::try_from(w).unwrap()))
+            .collect()
+    }
+
+    /// Returns all unique ngrams of `text` in the order of their first occurrence.
+    pub fn generate_unique(&self, text: &str) -> Vec<Ngram<N>> {
+        let mut seen = HashSet::new();
+        let mut ngrams = self.generate(text);
+        ngrams.retain(|i| seen.insert(*i));
+        ngrams
+    }
+
+    /// Returns a new insertion item with all unique ngrams of `text` as terms.
+    #[inline]
+    pub fn new_item<S>(&self, text: &str, store_item: S) -> NewItem<Ngram<N>, S> {
+        NewItem::new(self.generate_unique(text), store_item)
+    }
+
+    /// Returns the padded and case folded characters of `text`.
+    fn prepare(&self, text: &str) -> Vec<char> {
+        let pad_len = N.saturating_sub(1);
+        let mut chars = Vec::with_capacity(text.len() + pad_len * 2);
+
+        if let Some(pad) = self.start_padding {
+            chars.extend(std::iter::repeat(pad).take(pad_len));
+        }
+
+        if self.case_folding {
+            chars.extend(text.chars().flat_map(char::to_lowercase));
+        } else {
+            chars.extend(text.chars());
+        }
+
+        if let Some(pad) = self.end_padding {
+            chars.extend(std::iter::repeat(pad).take(pad_len));
+        }
+
+        chars
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ngrams<const N: usize>(strs: &[&str]) -> Vec<Ngram<N>> {
+        strs.iter().map(|i| Ngram::try_from(*i).unwrap()).collect()
+    }
+
+    #[test]
+    fn generate() {
+        let generator = NgramGenerator::<3>::new();
+        assert_eq!(generator.generate("kanji"), ngrams(&["kan", "anj", "nji"]));
+        assert_eq!(generator.generate("kan"), ngrams(&["kan"]));
+        assert!(generator.generate("ka").is_empty());
+        assert!(generator.generate("").is_empty());
+
+        let generator = NgramGenerator::<2>::new()
+            .with_start_padding('^')
+            .with_end_padding('$')
+            .with_case_folding();
+        assert_eq!(generator.generate("AbC"), ngrams(&["^a", "ab", "bc", "c$"]));
+
+        let generator = NgramGenerator::<3>::new().with_short_strings(ShortStrings::Fill('_'));
+        assert_eq!(generator.generate("漢"), ngrams(&["漢__"]));
+        assert_eq!(
+            generator.generate("漢字です"),
+            ngrams(&["漢字で", "字です"])
+        );
+    }
+
+    #[test]
+    fn unique() {
+        let generator = NgramGenerator::<2>::new();
+        assert_eq!(generator.generate("aaab").len(), 3);
+        assert_eq!(generator.generate_unique("aaab"), ngrams(&["aa", "ab"]));
+
+        let item = generator.new_item("aaab", 1u32);
+        assert_eq!(item.terms(), ngrams::<2>(&["aa", "ab"]));
+        assert_eq!(*item.store_item(), 1);
+    }
+}
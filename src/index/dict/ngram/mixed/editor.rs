@@ -0,0 +1,46 @@
+use super::MixedNGramDict;
+use super::MixedNgram;
+use crate::index::dict::IndexDictionaryEditor;
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+
+pub struct DictEditor<'a, B, const MAX: usize> {
+    ngram_dict: &'a mut MixedNGramDict<B, MAX>,
+}
+
+impl<'a, B, const MAX: usize> DictEditor<'a, B, MAX> {
+    #[inline]
+    pub(super) fn new(ngram_dict: &'a mut MixedNGramDict<B, MAX>) -> Self {
+        Self { ngram_dict }
+    }
+}
+
+impl<'a, B, const MAX: usize> IndexDictionaryEditor<MixedNgram<MAX>> for DictEditor<'a, B, MAX>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn announce_new_terms(&mut self, terms: usize, term_size: usize) -> Result<()> {
+        let mut map = self.ngram_dict.map_mut();
+        map.grow_to(map.len() + terms)?;
+        map.reserve_storage(terms, term_size * terms)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn insert_or_get_single(&mut self, term: &MixedNgram<MAX>) -> Result<u32> {
+        let id = {
+            let mut map = self.ngram_dict.map_mut();
+            if let Some(id) = map.get(term) {
+                return Ok(id);
+            }
+            let id = map.len();
+            map.insert(term, &(id as u32))?
+        };
+        // Keep the ngrams in the order of their IDs for reverse lookups.
+        self.ngram_dict
+            .terms_mut()
+            .extend(std::slice::from_ref(term));
+        Ok(id)
+    }
+}
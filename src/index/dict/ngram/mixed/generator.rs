@@ -0,0 +1,125 @@
+use crate::edit::NewItem;
+use crate::index::dict::ngram::mixed::term::MixedNgram;
+use std::collections::HashSet;
+
+/// Splits strings into overlapping ngrams of multiple lengths between a minimum length and MAX.
+/// Texts get indexed with all lengths while queries only use the longest length that fits into the
+/// query. This way short queries (eg. a single CJK character) can still be found while longer ones
+/// only need to look up the more selective long ngrams.
+#[derive(Debug, Clone, Copy)]
+pub struct MixedNgramGenerator<const MAX: usize> {
+    min_len: usize,
+    case_folding: bool,
+}
+
+impl<const MAX: usize> Default for MixedNgramGenerator<MAX> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min_len: 1,
+            case_folding: false,
+        }
+    }
+}
+
+impl<const MAX: usize> MixedNgramGenerator<MAX> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the length of the shortest ngrams that get indexed. Gets clamped between 1 and MAX.
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len.clamp(1, MAX.max(1));
+        self
+    }
+
+    /// Lowercases all strings before splitting them.
+    pub fn with_case_folding(mut self) -> Self {
+        self.case_folding = true;
+        self
+    }
+
+    /// Returns all unique ngrams of all lengths that should be indexed for `text`. Texts that are
+    /// shorter than the minimum length are indexed as a whole.
+    pub fn generate(&self, text: &str) -> Vec<MixedNgram<MAX>> {
+        let chars = self.prepare(text);
+        let min = self.min_len.min(chars.len());
+        let max = MAX.min(chars.len());
+
+        let mut seen = HashSet::new();
+        let mut out = vec![];
+        for n in min.max(1)..=max {
+            out.extend(windows(&chars, n).filter(|i| seen.insert(*i)));
+        }
+        out
+    }
+
+    /// Returns all unique ngrams that should be looked up for the query `text`. Only the longest
+    /// length that has been indexed for texts of the querys length is used.
+    pub fn query(&self, text: &str) -> Vec<MixedNgram<MAX>> {
+        let chars = self.prepare(text);
+        let n = MAX.min(chars.len());
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut seen = HashSet::new();
+        windows(&chars, n).filter(|i| seen.insert(*i)).collect()
+    }
+
+    /// Returns a new insertion item with the ngrams of `text` as terms.
+    #[inline]
+    pub fn new_item<S>(&self, text: &str, store_item: S) -> NewItem<MixedNgram<MAX>, S> {
+        NewItem::new(self.generate(text), store_item)
+    }
+
+    /// Returns the case folded characters of `text`.
+    fn prepare(&self, text: &str) -> Vec<char> {
+        if self.case_folding {
+            text.chars().flat_map(char::to_lowercase).collect()
+        } else {
+            text.chars().collect()
+        }
+    }
+}
+
+/// Returns all ngrams of `chars` with `n` characters.
+#[inline]
+fn windows<const MAX: usize>(
+    chars: &[char],
+    n: usize,
+) -> impl Iterator<Item = MixedNgram<MAX>> + '_ {
+    chars.windows(n).filter_map(MixedNgram::new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ngrams<const MAX: usize>(strs: &[&str]) -> Vec<MixedNgram<MAX>> {
+        strs.iter()
+            .map(|i| MixedNgram::try_from(*i).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generate() {
+        let generator = MixedNgramGenerator::<2>::new();
+        assert_eq!(
+            generator.generate("漢字漢"),
+            ngrams(&["漢", "字", "漢字", "字漢"])
+        );
+        assert_eq!(generator.query("漢"), ngrams(&["漢"]));
+        assert_eq!(generator.query("漢字漢"), ngrams(&["漢字", "字漢"]));
+        assert!(generator.query("").is_empty());
+
+        let generator = MixedNgramGenerator::<3>::new()
+            .with_min_len(3)
+            .with_case_folding();
+        assert_eq!(generator.generate("ABCD"), ngrams(&["abc", "bcd"]));
+        // Texts shorter than the minimum length are indexed as a whole.
+        assert_eq!(generator.generate("Ab"), ngrams(&["ab"]));
+        assert_eq!(generator.query("AB"), ngrams(&["ab"]));
+    }
+}
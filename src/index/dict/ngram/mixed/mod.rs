@@ -0,0 +1,175 @@
+pub mod editor;
+pub mod generator;
+pub mod term;
+
+use crate::index::dict::ngram::mixed::editor::DictEditor;
+use crate::index::dict::ngram::mixed::term::MixedNgram;
+use crate::index::dict::EditableIndexDictionary;
+use crate::index::dict::IndexDictionary;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::map::FMap;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+
+/// Entry of the map from ngrams to their IDs.
+const MAP_INDEX: usize = 0;
+/// Entry of the ngrams ordered by their IDs.
+const TERMS_INDEX: usize = 1;
+
+/// An index Dictionary for ngrams of different lengths up to MAX characters.
+pub struct MixedNGramDict<B, const MAX: usize> {
+    backend: MultiFile<B>,
+}
+
+impl<B, const MAX: usize> MixedNGramDict<B, MAX>
+where
+    B: Backend,
+{
+    /// Returns the map from ngrams to their IDs.
+    #[inline]
+    pub fn map(&self) -> FMap<GeneralSubBackend, MixedNgram<MAX>, u32> {
+        self.backend.get_backend(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    fn terms(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, const MAX: usize> MixedNGramDict<B, MAX>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn map_mut(&mut self) -> FMap<MFileEntryMut<B>, MixedNgram<MAX>, u32> {
+        self.backend.get_backend_mut(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn terms_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(TERMS_INDEX).unwrap()
+    }
+}
+
+impl<B, const MAX: usize> IndexDictionary<MixedNgram<MAX>> for MixedNGramDict<B, MAX>
+where
+    B: Backend,
+{
+    #[inline]
+    fn term_id(&self, term: &MixedNgram<MAX>) -> Option<u32> {
+        self.map().get(term)
+    }
+
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<MixedNgram<MAX>> {
+        self.terms().get_t(id as usize).ok()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.map().len()
+    }
+}
+
+impl<B, const MAX: usize> Creatable<B> for MixedNGramDict<B, MAX>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<FMap<_, MixedNgram<MAX>, u32>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B, const MAX: usize> Initiable<B> for MixedNGramDict<B, MAX>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B, const MAX: usize> EditableIndexDictionary<MixedNgram<MAX>> for MixedNGramDict<B, MAX>
+where
+    B: GrowableBackend,
+{
+    type Editor<'a> = DictEditor<'a, B, MAX> where Self: 'a;
+
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        DictEditor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::ngram::mixed::generator::MixedNgramGenerator;
+    use crate::index::dict::IndexDictionaryEditor;
+    use crate::index::preset::MixedNgramIndex;
+    use crate::retrieve::build::RetrieverBuilder;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn mixed_ngram_dict() {
+        let mut dict: MixedNGramDict<_, 3> = MixedNGramDict::create_mem_with_capacity(10).unwrap();
+
+        let terms: Vec<MixedNgram<3>> = ["a", "ab", "abc", "b", "漢字"]
+            .iter()
+            .map(|i| MixedNgram::try_from(*i).unwrap())
+            .collect();
+        let ids = dict.editor().insert_or_get(&terms).unwrap();
+        assert_eq!(dict.editor().insert_or_get(&terms).unwrap(), ids);
+
+        assert_eq!(dict.len(), terms.len());
+        for (term, id) in terms.iter().zip(ids.iter()) {
+            assert_eq!(dict.term_id(term), Some(*id));
+            assert_eq!(dict.term_by_id(*id).as_ref(), Some(term));
+        }
+        assert_eq!(dict.term_id(&MixedNgram::try_from("ba").unwrap()), None);
+    }
+
+    #[test]
+    fn mixed_ngram_index() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: MixedNgramIndex<_, u64, 2> = MixedNgramIndex::create(backend).unwrap();
+        let generator = MixedNgramGenerator::<2>::new();
+
+        let texts = ["漢字", "漢", "字典", "漢字辞典", "日本語"];
+        let mut editor = index.editor();
+        for (i, text) in texts.iter().enumerate() {
+            editor.insert(generator.new_item(text, i as u64)).unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let count = |query: &str| {
+            let mut retrieve_builder = RetrieverBuilder::new(&index);
+            retrieve_builder.add_terms(&generator.query(query));
+            retrieve_builder.count_intersection()
+        };
+
+        // Single characters are found by their unigrams, longer queries by their bigrams.
+        assert_eq!(count("漢"), 3);
+        assert_eq!(count("典"), 2);
+        assert_eq!(count("漢字"), 2);
+        assert_eq!(count("字典"), 1);
+        assert_eq!(count("日本語"), 1);
+        assert_eq!(count("本日"), 0);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::analysis::TermText;
+use crate::index::dict::ngram::ngram::Ngram;
+use bytestore::components::map::hashing::Hash;
+use serde::{Deserialize, Serialize};
+
+/// Character used to fill up the unused characters of shorter ngrams.
+const FILL: char = '\0';
+
+/// An ngram with a length between 1 and MAX characters. All ngrams use the same amount of space
+/// so they can be stored in the same dictionary.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MixedNgram<const MAX: usize> {
+    len: u8,
+    chars: Ngram<MAX>,
+}
+
+impl<const MAX: usize> MixedNgram<MAX> {
+    /// Creates a new ngram from the given characters. Returns `None` if there are no characters
+    /// or more than MAX.
+    pub fn new(chars: &[char]) -> Option<Self> {
+        if chars.is_empty() || chars.len() > MAX || MAX > u8::MAX as usize {
+            return None;
+        }
+        let mut buf = [FILL; MAX];
+        buf[..chars.len()].copy_from_slice(chars);
+        Some(Self {
+            len: chars.len() as u8,
+            chars: Ngram::from(buf),
+        })
+    }
+
+    /// Returns the characters of the ngram.
+    #[inline]
+    pub fn chars(&self) -> &[char] {
+        &self.chars.chars()[..self.len as usize]
+    }
+
+    /// Returns the amount of characters of the ngram.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Always returns `false` since ngrams have at least one character.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const MAX: usize> TermText for MixedNgram<MAX> {
+    #[inline]
+    fn text(&self) -> String {
+        self.chars().iter().collect()
+    }
+}
+
+impl<const MAX: usize> TryFrom<&str> for MixedNgram<MAX> {
+    type Error = ();
+
+    /// Creates an ngram of the whole string. Fails if the string is empty or longer than MAX.
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars: Vec<char> = value.chars().collect();
+        Self::new(&chars).ok_or(())
+    }
+}
+
+impl<const MAX: usize> TryFrom<String> for MixedNgram<MAX> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl<const MAX: usize> Hash for MixedNgram<MAX> {
+    #[inline]
+    fn hash(&self) -> u64 {
+        self.chars.hash().wrapping_add(self.len as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mixed_ngram() {
+        let ngram = MixedNgram::<3>::try_from("漢字").unwrap();
+        assert_eq!(ngram.chars(), ['漢', '字']);
+        assert_eq!(ngram.len(), 2);
+        assert_ne!(ngram, MixedNgram::<3>::try_from("漢").unwrap());
+
+        assert!(MixedNgram::<3>::try_from("").is_err());
+        assert!(MixedNgram::<3>::try_from("abcd").is_err());
+
+        let serialized = bitcode::serialize(&ngram).unwrap();
+        let deserialized: MixedNgram<3> = bitcode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, ngram);
+    }
+}
@@ -1,21 +1,26 @@
 pub mod editor;
+pub mod generator;
+pub mod mixed;
 
 #[allow(clippy::module_inception)]
 pub mod ngram;
 
+use crate::index::dict::layout::MapLayout;
 use crate::index::dict::ngram::editor::DictEditor;
 use crate::index::dict::ngram::ngram::Ngram;
 use crate::index::dict::EditableIndexDictionary;
 use crate::index::dict::IndexDictionary;
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
-use bytestore::components::map::FMap;
 use bytestore::traits::creatable::Creatable;
 use bytestore::traits::initiable::Initiable;
 
 /// An index Dictionary with a fixed string length.
+///
+/// Dictionaries created before reverse lookups existed can still be opened. Looking up their
+/// ngrams by ID has to scan all ngrams though.
 pub struct NGramDict<B, const N: usize> {
-    backend: FMap<B, Ngram<N>, u32>,
+    pub(crate) layout: MapLayout<B, Ngram<N>>,
 }
 
 impl<B, const N: usize> IndexDictionary<Ngram<N>> for NGramDict<B, N>
@@ -24,12 +29,17 @@ where
 {
     #[inline]
     fn term_id(&self, term: &Ngram<N>) -> Option<u32> {
-        self.backend.get(term)
+        self.layout.get(term)
+    }
+
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<Ngram<N>> {
+        self.layout.term_by_id(id)
     }
 
     #[inline]
     fn len(&self) -> usize {
-        self.backend.len()
+        self.layout.len()
     }
 }
 
@@ -39,8 +49,8 @@ where
 {
     #[inline]
     fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
-        let backend = FMap::with_capacity(backend, capacity)?;
-        Ok(Self { backend })
+        let layout = MapLayout::with_capacity(backend, capacity)?;
+        Ok(Self { layout })
     }
 }
 
@@ -50,8 +60,8 @@ where
 {
     #[inline]
     fn init(backend: B) -> bytestore::Result<Self> {
-        let backend = FMap::init(backend)?;
-        Ok(Self { backend })
+        let layout = MapLayout::init(backend)?;
+        Ok(Self { layout })
     }
 }
 
@@ -94,8 +104,16 @@ mod test {
             default_editor.insert_or_get_single(&term).unwrap();
         }
 
-        let ngram_len = ngram_dict.backend.backend.get(2).unwrap().len();
-        let dict_len = default_dict.map.backend.get(2).unwrap().len();
+        let ngram_len = ngram_dict.layout.map_data_len();
+        let dict_len = default_dict.layout.map_data_len();
         assert!(ngram_len < dict_len);
+
+        for (ngram, id) in ngram_dict.iter() {
+            assert_eq!(ngram_dict.term_id(&ngram), Some(id));
+        }
+        let term = Ngram::try_from("042").unwrap();
+        let id = ngram_dict.term_id(&term).unwrap();
+        assert_eq!(ngram_dict.term_by_id(id), Some(term));
+        assert_eq!(ngram_dict.iter().count(), 1000);
     }
 }
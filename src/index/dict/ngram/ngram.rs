@@ -1,3 +1,4 @@
+use crate::analysis::TermText;
 use bytestore::components::map::hashing::Hash;
 use serde::de::{SeqAccess, Visitor};
 use serde::ser::SerializeTuple;
@@ -8,6 +9,28 @@ use std::mem::MaybeUninit;
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Ngram<const N: usize>([char; N]);
 
+impl<const N: usize> Ngram<N> {
+    /// Returns the characters of the ngram.
+    #[inline]
+    pub fn chars(&self) -> &[char; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> TermText for Ngram<N> {
+    #[inline]
+    fn text(&self) -> String {
+        self.0.iter().collect()
+    }
+}
+
+impl<const N: usize> From<[char; N]> for Ngram<N> {
+    #[inline]
+    fn from(value: [char; N]) -> Self {
+        Self(value)
+    }
+}
+
 impl<const N: usize> TryFrom<&String> for Ngram<N> {
     type Error = ();
 
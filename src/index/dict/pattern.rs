@@ -0,0 +1,163 @@
+use crate::Result;
+use fst::Automaton;
+use regex_automata::{dense, DenseDFA, DFA};
+
+/// A compiled term pattern that matches whole terms. Patterns can either be wildcards, where `*`
+/// matches any amount of characters and `?` exactly one, or regular expressions. They get compiled
+/// into an automaton that can be intersected with ordered dictionaries.
+pub struct TermPattern {
+    dfa: DenseDFA<Vec<usize>, usize>,
+
+    /// A literal that all matching terms have to start with. Used to skip over terms that can't
+    /// match.
+    literal_prefix: String,
+}
+
+impl TermPattern {
+    /// Compiles a wildcard pattern like `ka*ji`. `*` matches any amount of characters and `?`
+    /// matches a single character. All other characters are matched literally.
+    pub fn wildcard(pattern: &str) -> Result<Self> {
+        let mut regex = String::with_capacity(pattern.len() * 2 + 4);
+        regex.push_str("(?s)");
+        for c in pattern.chars() {
+            match c {
+                '*' => regex.push_str(".*"),
+                '?' => regex.push('.'),
+                c => {
+                    if is_meta_character(c) {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+            }
+        }
+
+        let literal_prefix = pattern
+            .split(|c| c == '*' || c == '?')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        Ok(Self {
+            dfa: build_dfa(&regex)?,
+            literal_prefix,
+        })
+    }
+
+    /// Compiles a regular expression. The expression has to match the whole term.
+    pub fn regex(pattern: &str) -> Result<Self> {
+        Ok(Self {
+            dfa: build_dfa(pattern)?,
+            literal_prefix: String::new(),
+        })
+    }
+
+    /// Returns a literal that all matching terms start with. Might be empty.
+    #[inline]
+    pub fn literal_prefix(&self) -> &str {
+        &self.literal_prefix
+    }
+
+    /// Returns `true` if the pattern matches the whole term.
+    pub fn matches(&self, term: &str) -> bool {
+        let mut state = self.start();
+        for b in term.as_bytes() {
+            if !self.can_match(&state) {
+                return false;
+            }
+            state = self.accept(&state, *b);
+        }
+        self.is_match(&state)
+    }
+}
+
+impl Automaton for TermPattern {
+    type State = usize;
+
+    #[inline]
+    fn start(&self) -> Self::State {
+        self.dfa.start_state()
+    }
+
+    #[inline]
+    fn is_match(&self, state: &Self::State) -> bool {
+        self.dfa.is_match_state(*state)
+    }
+
+    #[inline]
+    fn can_match(&self, state: &Self::State) -> bool {
+        !self.dfa.is_dead_state(*state)
+    }
+
+    #[inline]
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        self.dfa.next_state(*state, byte)
+    }
+}
+
+/// Builds an anchored DFA for the given regular expression.
+fn build_dfa(regex: &str) -> Result<DenseDFA<Vec<usize>, usize>> {
+    Ok(dense::Builder::new().anchored(true).build(regex)?)
+}
+
+/// Returns `true` if `c` has a special meaning in regular expressions and needs to be escaped.
+#[inline]
+fn is_meta_character(c: char) -> bool {
+    matches!(
+        c,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wildcard() {
+        let pattern = TermPattern::wildcard("ka*ji").unwrap();
+        assert_eq!(pattern.literal_prefix(), "ka");
+        assert!(pattern.matches("kanji"));
+        assert!(pattern.matches("kaji"));
+        assert!(!pattern.matches("kanjis"));
+        assert!(!pattern.matches("kan"));
+
+        let pattern = TermPattern::wildcard("漢?").unwrap();
+        assert!(pattern.matches("漢字"));
+        assert!(!pattern.matches("漢"));
+        assert!(!pattern.matches("漢字字"));
+
+        // Regex meta characters are matched literally.
+        let pattern = TermPattern::wildcard("a.b(*").unwrap();
+        assert_eq!(pattern.literal_prefix(), "a.b(");
+        assert!(pattern.matches("a.b(c"));
+        assert!(!pattern.matches("axb(c"));
+    }
+
+    #[test]
+    fn regex() {
+        let pattern = TermPattern::regex("ka(n|nj)i[0-9]+").unwrap();
+        assert!(pattern.matches("kani1"));
+        assert!(pattern.matches("kanji42"));
+        assert!(!pattern.matches("kanji"));
+        assert!(!pattern.matches("xkani1"));
+
+        assert!(TermPattern::regex("ka(").is_err());
+    }
+}
@@ -1,21 +1,36 @@
 pub mod editor;
 pub mod item;
 
+use crate::index::dict::pattern::TermPattern;
 use crate::index::dict::sorted::editor::DictEditor;
 use crate::index::dict::sorted::item::DictItem;
-use crate::index::dict::{EditIndexDictionary, IndexDictionary};
-use crate::traits::deser::Deser;
+use crate::index::dict::{
+    fuzzy, EditableIndexDictionary, FuzzyDictionary, IndexDictionary, PatternDictionary,
+    PrefixDictionary, RangeDictionary,
+};
+use bytestore::backend::base::sub::GeneralSubBackend;
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
 use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
 use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
 use bytestore::traits::initiable::Initiable;
 use std::cmp::Ordering;
 use std::marker::PhantomData;
+use std::ops::Bound;
 
-/// A general sorted index dictionary implementation.
+/// Entry of the dictionary items sorted by their terms.
+const ITEMS_INDEX: usize = 0;
+/// Entry of the terms ordered by their IDs.
+const TERMS_INDEX: usize = 1;
+
+/// A general sorted index dictionary implementation. Terms are kept in order which allows ordered
+/// operations like range or prefix lookups that hash based dictionaries can't provide.
 pub struct SortedDict<B, T> {
-    backend: IndexedFile<B>,
+    backend: MultiFile<B>,
     _p1: PhantomData<T>,
 }
 
@@ -24,8 +39,12 @@ where
     B: GrowableBackend,
 {
     fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
         Ok(Self {
-            backend: IndexedFile::with_capacity(backend, capacity)?,
+            backend,
             _p1: PhantomData,
         })
     }
@@ -38,21 +57,45 @@ where
     #[inline]
     fn init(backend: B) -> bytestore::Result<Self> {
         Ok(Self {
-            backend: IndexedFile::init(backend)?,
+            backend: MultiFile::init(backend)?,
             _p1: PhantomData,
         })
     }
 }
 
+impl<B, T> SortedDict<B, T>
+where
+    B: Backend,
+{
+    /// Returns all dictionary items sorted by their terms. Operations accessing multiple items
+    /// should fetch them once and reuse them.
+    #[inline]
+    pub(crate) fn sorted_items(&self) -> SortedItems<'_, T> {
+        SortedItems {
+            items: self.backend.get_backend(ITEMS_INDEX).unwrap(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns all terms ordered by their IDs.
+    #[inline]
+    fn terms(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(TERMS_INDEX).unwrap()
+    }
+}
+
 impl<B, T> SortedDict<B, T>
 where
     B: GrowableBackend,
 {
-    /// Creates a new `DictEditor` that can be used to insert new terms into this dictionary. This
-    /// can and should be reused as it shares its allocation across insertion calls.
     #[inline]
-    pub fn dict_editor(&mut self) -> DictEditor<B, T> {
-        DictEditor::new(self)
+    pub(crate) fn items_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(ITEMS_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn terms_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(TERMS_INDEX).unwrap()
     }
 }
 
@@ -61,24 +104,57 @@ where
     B: Backend,
     T: Deser,
 {
-    /// Returns an Dictionary Item at the given position (not its ID!)
+    /// Returns an iterator over all terms and their IDs in the terms order, starting at the given
+    /// position.
+    #[inline]
+    pub fn iter_sorted_from(&self, pos: usize) -> impl Iterator<Item = (T, u32)> + '_ {
+        self.sorted_items().into_iter_from(pos)
+    }
+
+    /// Returns an iterator over all terms and their IDs in the terms order.
+    #[inline]
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (T, u32)> + '_ {
+        self.iter_sorted_from(0)
+    }
+}
+
+/// The items of a [`SortedDict`] sorted by their terms.
+pub(crate) struct SortedItems<'a, T> {
+    items: IndexedFile<GeneralSubBackend<'a>>,
+    _p1: PhantomData<T>,
+}
+
+impl<'a, T> SortedItems<'a, T>
+where
+    T: Deser,
+{
+    /// Returns the amount of items.
     #[inline]
-    fn dict_item_at(&self, pos: usize) -> Option<DictItem<T>> {
-        let data = self.backend.get(pos).ok()?;
+    pub(crate) fn len(&self) -> usize {
+        self.items.count()
+    }
+
+    /// Returns the item at the given position.
+    #[inline]
+    pub(crate) fn item_at(&self, pos: usize) -> Option<DictItem<T>> {
+        let data = self.items.get(pos).ok()?;
         bitcode::deserialize(data).ok()
     }
 
-    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    /// Binary searches the position of a term using the given comparator. Returns the position of
+    /// the first matching term or the position where a term would have to be inserted to keep
+    /// the items sorted.
+    pub(crate) fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
     where
         F: FnMut(&T) -> Ordering,
     {
-        let mut size = self.backend.count();
+        let mut size = self.len();
         let mut left = 0;
         let mut right = size;
         while left < right {
             let mid = left + size / 2;
 
-            let cmp = f(self.dict_item_at(mid).unwrap().item());
+            let cmp = f(self.item_at(mid).unwrap().item());
 
             if cmp == Ordering::Less {
                 left = mid + 1;
@@ -93,15 +169,27 @@ where
 
         Err(left)
     }
+
+    /// Returns an iterator over all terms and their IDs in the terms order, starting at the given
+    /// position.
+    pub(crate) fn into_iter_from(self, pos: usize) -> impl Iterator<Item = (T, u32)> + 'a
+    where
+        T: 'a,
+    {
+        (pos..self.len()).map(move |pos| {
+            let item = self.item_at(pos).unwrap();
+            let id = item.id();
+            (item.into_inner(), id)
+        })
+    }
 }
 
-impl<B, T> SortedDict<B, T>
+impl<'a, T> SortedItems<'a, T>
 where
-    B: Backend,
     T: Deser + Ord,
 {
     #[inline]
-    fn binary_search(&self, item: &T) -> Result<usize, usize> {
+    pub(crate) fn binary_search(&self, item: &T) -> Result<usize, usize> {
         self.binary_search_by(|i| i.cmp(item))
     }
 }
@@ -109,16 +197,239 @@ where
 impl<B, T> IndexDictionary<T> for SortedDict<B, T>
 where
     B: Backend,
-    T: Deser + Ord + Eq,
+    T: Deser + Ord,
 {
+    #[inline]
     fn term_id(&self, term: &T) -> Option<u32> {
-        let item_pos = self.binary_search_by(|i| i.cmp(term)).ok()?;
-        let ditem = self.dict_item_at(item_pos).unwrap();
+        let items = self.sorted_items();
+        let item_pos = items.binary_search(term).ok()?;
+        let ditem = items.item_at(item_pos).unwrap();
         Some(ditem.id())
     }
 
+    #[inline]
+    fn term_by_id(&self, id: u32) -> Option<T> {
+        self.terms().get_t(id as usize).ok()
+    }
+
     #[inline]
     fn len(&self) -> usize {
-        self.backend.count()
+        self.sorted_items().len()
+    }
+}
+
+impl<B, T> RangeDictionary<T> for SortedDict<B, T>
+where
+    B: Backend,
+    T: Deser + Ord,
+{
+    fn range_term_ids(&self, start: Bound<&T>, end: Bound<&T>, limit: usize) -> Vec<u32> {
+        let items = self.sorted_items();
+        let start = match start {
+            Bound::Included(s) => items.binary_search(s).unwrap_or_else(|i| i),
+            Bound::Excluded(s) => items.binary_search(s).map(|i| i + 1).unwrap_or_else(|i| i),
+            Bound::Unbounded => 0,
+        };
+        items
+            .into_iter_from(start)
+            .take_while(|(t, _)| match end {
+                Bound::Included(e) => t <= e,
+                Bound::Excluded(e) => t < e,
+                Bound::Unbounded => true,
+            })
+            .take(limit)
+            .map(|(_, id)| id)
+            .collect()
+    }
+}
+
+impl<B> PrefixDictionary for SortedDict<B, String>
+where
+    B: Backend,
+{
+    fn prefix_term_ids(&self, prefix: &str, limit: usize) -> Vec<u32> {
+        let items = self.sorted_items();
+        let start = items
+            .binary_search_by(|i| i.as_str().cmp(prefix))
+            .unwrap_or_else(|i| i);
+        items
+            .into_iter_from(start)
+            .take_while(|(term, _)| term.starts_with(prefix))
+            .take(limit)
+            .map(|(_, id)| id)
+            .collect()
+    }
+}
+
+impl<B> PatternDictionary for SortedDict<B, String>
+where
+    B: Backend,
+{
+    /// Only terms starting with the patterns literal prefix are matched, so patterns that start
+    /// with a wildcard or regex need to scan all terms.
+    fn pattern_term_ids(&self, pattern: &TermPattern, limit: usize) -> Vec<u32> {
+        let prefix = pattern.literal_prefix();
+        let items = self.sorted_items();
+        let start = items
+            .binary_search_by(|i| i.as_str().cmp(prefix))
+            .unwrap_or_else(|i| i);
+        items
+            .into_iter_from(start)
+            .take_while(|(t, _)| t.starts_with(prefix))
+            .filter(|(t, _)| pattern.matches(t))
+            .take(limit)
+            .map(|(_, id)| id)
+            .collect()
+    }
+}
+
+impl<B> FuzzyDictionary for SortedDict<B, String>
+where
+    B: Backend,
+{
+    /// Only terms starting with the exact prefix are compared so longer prefixes are a lot
+    /// faster.
+    fn fuzzy_term_ids(
+        &self,
+        term: &str,
+        max_distance: u32,
+        prefix_len: usize,
+        limit: usize,
+    ) -> Vec<(u32, u32)> {
+        let prefix = fuzzy::prefix(term, prefix_len);
+        let items = self.sorted_items();
+        let start = items
+            .binary_search_by(|i| i.as_str().cmp(prefix))
+            .unwrap_or_else(|i| i);
+        let matches = items
+            .into_iter_from(start)
+            .take_while(|(t, _)| t.starts_with(prefix))
+            .filter_map(|(t, id)| Some((id, fuzzy::distance(term, &t, max_distance)?)));
+        fuzzy::closest(matches, limit)
+    }
+}
+
+impl<B, T> EditableIndexDictionary<T> for SortedDict<B, T>
+where
+    B: GrowableBackend,
+    T: Deser + Ord,
+{
+    type Editor<'a> = DictEditor<'a, B, T> where T: 'a, B: 'a, Self: 'a;
+
+    /// Returns an editor for the dictionary.
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        DictEditor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::dict::IndexDictionaryEditor;
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn sorted_dict() {
+        let mut dict: SortedDict<_, String> = SortedDict::create_mem_with_capacity(10).unwrap();
+
+        let terms: Vec<_> = (0..1000).rev().map(|i| format!("{i}")).collect();
+        let mut editor = dict.editor();
+        let ids = editor.insert_or_get(&terms).unwrap();
+        assert_eq!(editor.insert_or_get_single(&terms[10]).unwrap(), ids[10]);
+
+        assert_eq!(dict.len(), terms.len());
+        for (term, id) in terms.iter().zip(ids.iter()) {
+            assert_eq!(dict.term_id(term), Some(*id));
+            assert_eq!(dict.term_by_id(*id).as_ref(), Some(term));
+        }
+        assert_eq!(dict.term_id(&"abc".to_string()), None);
+
+        let mut sorted = terms.clone();
+        sorted.sort_unstable();
+        let iterated: Vec<_> = dict.iter_sorted().map(|i| i.0).collect();
+        assert_eq!(iterated, sorted);
+
+        // Iterating in ID order returns the terms in insertion order.
+        let iterated: Vec<_> = dict.iter().map(|i| i.0).collect();
+        assert_eq!(iterated, terms);
+    }
+
+    #[test]
+    fn prefix() {
+        let mut dict: SortedDict<_, String> = SortedDict::create_mem_with_capacity(10).unwrap();
+
+        let terms = ["kana", "kanji", "kan", "ka", "kbc", "jotoba", "kanjis"].map(String::from);
+        dict.editor().insert_or_get(&terms).unwrap();
+
+        let prefixed = |prefix: &str, limit: usize| -> Vec<String> {
+            dict.prefix_term_ids(prefix, limit)
+                .into_iter()
+                .map(|id| dict.term_by_id(id).unwrap())
+                .collect()
+        };
+
+        assert_eq!(prefixed("kan", 10), ["kan", "kana", "kanji", "kanjis"]);
+        assert_eq!(prefixed("kan", 2), ["kan", "kana"]);
+        assert_eq!(prefixed("kanjis", 10), ["kanjis"]);
+        assert_eq!(prefixed("x", 10), Vec::<String>::new());
+        assert_eq!(prefixed("", 10).len(), terms.len());
+    }
+
+    #[test]
+    fn fuzzy() {
+        let mut dict: SortedDict<_, String> = SortedDict::create_mem_with_capacity(10).unwrap();
+
+        let terms = ["kana", "kanji", "kan", "ka", "kbc", "jotoba", "kanjis"].map(String::from);
+        let ids = dict.editor().insert_or_get(&terms).unwrap();
+
+        let res = dict.fuzzy_term_ids("kanij", 2, 2, 10);
+        assert_eq!(res, [(ids[0], 2), (ids[1], 2), (ids[2], 2), (ids[6], 2)]);
+        assert_eq!(dict.fuzzy_term_ids("kanji", 2, 2, 1), [(ids[1], 0)]);
+        assert_eq!(dict.fuzzy_term_ids("kbc", 1, 0, 10), [(ids[4], 0)]);
+        assert!(dict.fuzzy_term_ids("janji", 2, 1, 10).is_empty());
+    }
+
+    #[test]
+    fn pattern() {
+        let mut dict: SortedDict<_, String> = SortedDict::create_mem_with_capacity(10).unwrap();
+
+        let terms = ["kana", "kanji", "kaji", "ka", "kbc", "jotoba", "kanjis"].map(String::from);
+        let ids = dict.editor().insert_or_get(&terms).unwrap();
+
+        let pattern = TermPattern::wildcard("ka*ji").unwrap();
+        assert_eq!(dict.pattern_term_ids(&pattern, 10), [ids[2], ids[1]]);
+        assert_eq!(dict.pattern_term_ids(&pattern, 1), [ids[2]]);
+
+        let pattern = TermPattern::regex("k[a-z]{2}").unwrap();
+        assert_eq!(dict.pattern_term_ids(&pattern, 10), [ids[4]]);
+    }
+
+    #[test]
+    fn range() {
+        let mut dict: SortedDict<_, u32> = SortedDict::create_mem_with_capacity(10).unwrap();
+
+        let terms: Vec<u32> = (0..100).rev().collect();
+        let ids = dict.editor().insert_or_get(&terms).unwrap();
+        let id_of = |t: u32| ids[99 - t as usize];
+
+        let res = dict.range_term_ids(Bound::Included(&10), Bound::Excluded(&13), 100);
+        assert_eq!(res, [id_of(10), id_of(11), id_of(12)]);
+
+        let res = dict.range_term_ids(Bound::Excluded(&10), Bound::Included(&13), 100);
+        assert_eq!(res, [id_of(11), id_of(12), id_of(13)]);
+
+        let res = dict.range_term_ids(Bound::Excluded(&97), Bound::Unbounded, 100);
+        assert_eq!(res, [id_of(98), id_of(99)]);
+
+        let all = dict.range_term_ids(Bound::Unbounded, Bound::Unbounded, 100);
+        assert_eq!(all.len(), 100);
+        assert_eq!(
+            dict.range_term_ids(Bound::Unbounded, Bound::Excluded(&50), 5),
+            (0..5).map(id_of).collect::<Vec<_>>()
+        );
+        assert!(dict
+            .range_term_ids(Bound::Included(&200), Bound::Unbounded, 100)
+            .is_empty());
     }
 }
@@ -0,0 +1,64 @@
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+
+/// Makes sure `ifile` has an entry with the ID `id` by pushing empty entries if necessary.
+pub(crate) fn ensure_entry<B>(ifile: &mut IndexedFile<B>, id: usize) -> Result<()>
+where
+    B: GrowableBackend,
+{
+    let count = ifile.count();
+    if id < count {
+        return Ok(());
+    }
+    let need_insert = (id + 1) - count;
+    ifile.push_n_empty(need_insert)?;
+    Ok(())
+}
+
+/// Returns the length of the entry at `id` or 0 if there is no such entry.
+#[inline]
+pub(crate) fn entry_len<B>(ifile: &IndexedFile<B>, id: usize) -> usize
+where
+    B: Backend,
+{
+    ifile.get(id).map(|i| i.len()).unwrap_or(0)
+}
+
+/// Overwrites the data of all given entries in place. Entries that are too small to hold the new
+/// data get grown first, larger entries keep their trailing bytes. Because of this, the written
+/// data has to encode its own length.
+pub(crate) fn overwrite_entries<B>(
+    ifile: &mut IndexedFile<B>,
+    mut entries: Vec<(usize, Vec<u8>)>,
+) -> Result<()>
+where
+    B: GrowableBackend,
+{
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let Some(max_id) = entries.last().map(|i| i.0) else {
+        return Ok(());
+    };
+    ensure_entry(ifile, max_id)?;
+
+    // Grow all entries at once to not need a lot of small allocations.
+    let growth: Vec<(usize, Vec<u8>)> = entries
+        .iter()
+        .filter_map(|(id, data)| {
+            let len = entry_len(ifile, *id);
+            (data.len() > len).then(|| (*id, vec![0u8; data.len() - len]))
+        })
+        .collect();
+    if !growth.is_empty() {
+        ifile.grow_multiple_fast(&growth)?;
+    }
+
+    for (id, data) in entries {
+        let mut backend = ifile.get_backend_mut(id)?;
+        backend.data_mut()[..data.len()].copy_from_slice(&data);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::analysis::config::AnalyzerConfig;
+use crate::index::entry::overwrite_entries;
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Entry holding the [`AnalyzerConfig`] of the index.
+pub(crate) const ANALYZER_ENTRY: usize = 0;
+/// Entry holding the names of the posting lists of the index.
+pub(crate) const POSTING_LISTS_ENTRY: usize = 1;
+
+/// Size of the length prefix of each value.
+const LEN_SIZE: usize = 8;
+
+/// Metadata of an index like the analyzer configuration texts have been indexed with. Each value
+/// is stored serialized in its own entry.
+pub struct IndexMeta<B> {
+    pub backend: IndexedFile<B>,
+}
+
+impl<B> Initiable<B> for IndexMeta<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = IndexedFile::init(backend)?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B> Creatable<B> for IndexMeta<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let backend = IndexedFile::with_capacity(backend, capacity)?;
+        Ok(Self { backend })
+    }
+}
+
+impl<B> IndexMeta<B>
+where
+    B: Backend,
+{
+    /// Returns the analyzer config of the index or `None` if none has been set.
+    #[inline]
+    pub fn analyzer_config(&self) -> Option<AnalyzerConfig> {
+        self.get_value(ANALYZER_ENTRY)
+    }
+
+    /// Returns the names of the posting lists of the index. The name at position `i` belongs to
+    /// posting list `i`.
+    #[inline]
+    pub fn posting_lists(&self) -> Vec<String> {
+        self.get_value(POSTING_LISTS_ENTRY).unwrap_or_default()
+    }
+
+    /// Returns the value stored in `entry` or `None` if it hasn't been set.
+    pub(crate) fn get_value<V: DeserializeOwned>(&self, entry: usize) -> Option<V> {
+        let data = self.backend.get(entry).ok()?;
+        if data.len() < LEN_SIZE {
+            return None;
+        }
+        let len = u64::from_le_bytes(data[..LEN_SIZE].try_into().unwrap()) as usize;
+        bitcode::deserialize(data.get(LEN_SIZE..LEN_SIZE + len)?).ok()
+    }
+}
+
+impl<B> IndexMeta<B>
+where
+    B: GrowableBackend,
+{
+    /// Sets the analyzer config of the index.
+    #[inline]
+    pub fn set_analyzer_config(&mut self, config: &AnalyzerConfig) -> Result<()> {
+        self.set_value(ANALYZER_ENTRY, config)
+    }
+
+    /// Sets the names of the posting lists of the index.
+    #[inline]
+    pub fn set_posting_lists(&mut self, names: &[String]) -> Result<()> {
+        self.set_value(POSTING_LISTS_ENTRY, &names)
+    }
+
+    /// Stores `value` in `entry`, replacing the previous value.
+    pub(crate) fn set_value<V: Serialize>(&mut self, entry: usize, value: &V) -> Result<()> {
+        let value = bitcode::serialize(value)?;
+        // Entries keep their trailing bytes when overwritten with smaller values so the length
+        // has to be stored too.
+        let mut data = Vec::with_capacity(LEN_SIZE + value.len());
+        data.extend_from_slice(&(value.len() as u64).to_le_bytes());
+        data.extend_from_slice(&value);
+        overwrite_entries(&mut self.backend, vec![(entry, data)])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::analysis::config::FilterConfig;
+    use crate::analysis::language::Language;
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn analyzer_config() {
+        let mut meta = IndexMeta::create_mem_with_capacity(0).unwrap();
+        assert_eq!(meta.analyzer_config(), None);
+
+        let stopwords = Language::English.stopwords().iter().map(|i| i.to_string());
+        let config = AnalyzerConfig::for_language(Language::English)
+            .with_filter(FilterConfig::CustomStopwords(stopwords.collect()));
+        meta.set_analyzer_config(&config).unwrap();
+        assert_eq!(meta.analyzer_config(), Some(config));
+
+        // Overwrite with a smaller config.
+        let config = AnalyzerConfig::for_language(Language::German);
+        meta.set_analyzer_config(&config).unwrap();
+        assert_eq!(meta.analyzer_config(), Some(config));
+    }
+
+    #[test]
+    fn posting_lists() {
+        let mut meta = IndexMeta::create_mem_with_capacity(0).unwrap();
+        assert!(meta.posting_lists().is_empty());
+
+        let names = ["reading", "kanji", "gloss"].map(|i| i.to_string());
+        meta.set_posting_lists(&names).unwrap();
+        assert_eq!(meta.posting_lists(), names);
+        assert_eq!(meta.analyzer_config(), None);
+    }
+}
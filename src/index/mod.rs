@@ -1,12 +1,17 @@
 pub mod dict;
+pub(crate) mod entry;
+pub mod meta;
 pub mod posting;
 pub mod preset;
 pub mod storage;
+pub mod synonym;
 pub mod traits;
 
 pub(crate) const DICT_INDEX: usize = 0;
 pub(crate) const STORAGE_INDEX: usize = 1;
 pub(crate) const POSTINGS_INDEX: usize = 2;
+pub(crate) const META_INDEX: usize = 3;
+pub(crate) const SYNONYMS_INDEX: usize = 4;
 
 #[macro_export]
 macro_rules! implement_index_dict_trait {
@@ -21,6 +26,13 @@ macro_rules! implement_index_dict_trait {
         fn get_dict(&self) -> Self::DictImpl<'_> {
             self.dict()
         }
+
+        #[inline]
+        fn get_synonyms(
+            &self,
+        ) -> Option<$crate::index::synonym::SynonymMap<GeneralSubBackend<'_>, $t>> {
+            self.synonyms()
+        }
     };
 }
 
@@ -50,6 +62,11 @@ macro_rules! implement_index_trait {
         fn get_postings(&self) -> Self::PostingsImpl<'_> {
             self.postings()
         }
+
+        #[inline]
+        fn get_meta(&self) -> Option<$crate::index::meta::IndexMeta<GeneralSubBackend<'_>>> {
+            self.meta()
+        }
     };
 }
 
@@ -98,7 +115,11 @@ macro_rules! index_functions {
     ($dict_impl:ident, $storage_impl:ident, $postings_impl:ident, $t:tt, $s:tt) => {
         #[inline]
         pub fn editor(&mut self) -> IndexEditor<Self, B, $t, $s> {
+            let analyzer = self.analyzer();
+            let posting_lists = self.posting_lists();
             IndexEditor::new(self)
+                .with_analyzer(analyzer)
+                .with_posting_lists(posting_lists)
         }
 
         #[inline]
@@ -116,6 +137,35 @@ macro_rules! index_functions {
             self.backend.get_backend(POSTINGS_INDEX).unwrap()
         }
 
+        /// Returns the metadata of the index. Indexes created without metadata return `None`.
+        #[inline]
+        pub fn meta(&self) -> Option<$crate::index::meta::IndexMeta<GeneralSubBackend>> {
+            self.backend.get_backend(META_INDEX)
+        }
+
+        /// Returns the analyzer configured for the index or the default analyzer.
+        pub fn analyzer(&self) -> $crate::analysis::Analyzer {
+            self.meta()
+                .and_then(|i| i.analyzer_config())
+                .map(|i| i.build())
+                .unwrap_or_default()
+        }
+
+        /// Returns the names of the posting lists of the index. The name at position `i` belongs
+        /// to posting list `i`.
+        #[inline]
+        pub fn posting_lists(&self) -> Vec<String> {
+            self.meta().map(|i| i.posting_lists()).unwrap_or_default()
+        }
+
+        /// Returns the synonyms of the index. Indexes created without synonyms return `None`.
+        #[inline]
+        pub fn synonyms(
+            &self,
+        ) -> Option<$crate::index::synonym::SynonymMap<GeneralSubBackend, $t>> {
+            self.backend.get_backend(SYNONYMS_INDEX)
+        }
+
         #[inline]
         pub fn flush(&mut self) -> crate::Result<()> {
             self.backend.flush()?;
@@ -136,6 +186,8 @@ macro_rules! index_mut_functions {
             mf.insert_new_backend::<$dict_impl<_, $t>>()?;
             mf.insert_new_backend::<$storage_impl<_, $s>>()?;
             mf.insert_new_backend::<$postings_impl<_>>()?;
+            mf.insert_new_backend::<$crate::index::meta::IndexMeta<_>>()?;
+            mf.insert_new_backend::<$crate::index::synonym::SynonymMap<_, $t>>()?;
             Ok(Self {
                 backend: mf,
                 p: std::marker::PhantomData,
@@ -179,19 +231,115 @@ macro_rules! index_mut_functions {
             let entry = self.backend.entry_mut(POSTINGS_INDEX).unwrap();
             $postings_impl::init(entry).unwrap()
         }
+
+        /// Returns the mutable metadata of the index. Indexes created without metadata get it
+        /// added.
+        pub fn meta_mut(
+            &mut self,
+        ) -> crate::Result<$crate::index::meta::IndexMeta<MFileEntryMut<'_, B>>>
+        where
+            B: GrowableBackend,
+        {
+            if !self.backend.has_id(META_INDEX) {
+                self.backend
+                    .insert_new_backend::<$crate::index::meta::IndexMeta<_>>()?;
+            }
+            let entry = self.backend.entry_mut(META_INDEX)?;
+            Ok($crate::index::meta::IndexMeta::init(entry)?)
+        }
+
+        /// Sets the analyzer config of the index. Editors and retrievers created afterwards use
+        /// the analyzer built from it for texts.
+        #[inline]
+        pub fn set_analyzer_config(
+            &mut self,
+            config: &$crate::analysis::config::AnalyzerConfig,
+        ) -> crate::Result<()>
+        where
+            B: GrowableBackend,
+        {
+            self.meta_mut()?.set_analyzer_config(config)
+        }
+
+        /// Names the posting lists of the index. The name at position `i` belongs to posting list
+        /// `i`. Document fields are named posting lists too. Existing names can't be changed or
+        /// removed, only new names can be appended.
+        pub fn set_posting_lists(&mut self, names: &[&str]) -> crate::Result<()>
+        where
+            B: GrowableBackend,
+        {
+            if names.len() > u16::MAX as usize {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            let mut meta = self.meta_mut()?;
+            let existing = meta.posting_lists();
+            if existing.len() > names.len() || existing.iter().zip(names).any(|(a, b)| a != b) {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            for (pos, name) in names.iter().enumerate() {
+                if names[..pos].contains(name) {
+                    return Err(crate::error::Error::DuplicateEntry);
+                }
+            }
+            let names: Vec<String> = names.iter().map(|i| i.to_string()).collect();
+            meta.set_posting_lists(&names)
+        }
+
+        /// Returns the ID of the posting list `name`, appending a new posting list if the index
+        /// doesn't have one with that name yet.
+        pub fn add_posting_list(&mut self, name: &str) -> crate::Result<u16>
+        where
+            B: GrowableBackend,
+        {
+            let mut meta = self.meta_mut()?;
+            let mut names = meta.posting_lists();
+            if let Some(pos) = names.iter().position(|i| i == name) {
+                return Ok(pos as u16);
+            }
+            if names.len() >= u16::MAX as usize {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            names.push(name.to_string());
+            meta.set_posting_lists(&names)?;
+            Ok(names.len() as u16 - 1)
+        }
+
+        /// Returns the mutable synonyms of the index. Indexes created without synonyms get them
+        /// added.
+        pub fn synonyms_mut(
+            &mut self,
+        ) -> crate::Result<$crate::index::synonym::SynonymMap<MFileEntryMut<'_, B>, $t>>
+        where
+            B: GrowableBackend,
+        {
+            if !self.backend.has_id(SYNONYMS_INDEX) {
+                self.meta_mut()?;
+                self.backend
+                    .insert_new_backend::<$crate::index::synonym::SynonymMap<_, $t>>()?;
+            }
+            let entry = self.backend.entry_mut(SYNONYMS_INDEX)?;
+            Ok($crate::index::synonym::SynonymMap::init(entry)?)
+        }
     };
 }
 
 #[macro_export]
 macro_rules! ngram_index_functions {
     ($storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
+        $crate::ngram_index_functions!(NGramDict, Ngram, $storage_impl, $postings_impl, $n, $s);
+    };
+    ($dict_impl:ident, $term:ident, $storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
         #[inline]
-        pub fn editor(&mut self) -> IndexEditor<Self, B, Ngram<$n>, $s> {
+        pub fn editor(&mut self) -> IndexEditor<Self, B, $term<$n>, $s> {
+            let analyzer = self.analyzer();
+            let posting_lists = self.posting_lists();
             IndexEditor::new(self)
+                .with_analyzer(analyzer)
+                .with_posting_lists(posting_lists)
         }
 
         #[inline]
-        pub fn dict(&self) -> NGramDict<GeneralSubBackend, $n> {
+        pub fn dict(&self) -> $dict_impl<GeneralSubBackend, $n> {
             self.backend.get_backend(DICT_INDEX).unwrap()
         }
 
@@ -205,6 +353,27 @@ macro_rules! ngram_index_functions {
             self.backend.get_backend(POSTINGS_INDEX).unwrap()
         }
 
+        /// Returns the metadata of the index. Indexes created without metadata return `None`.
+        #[inline]
+        pub fn meta(&self) -> Option<$crate::index::meta::IndexMeta<GeneralSubBackend>> {
+            self.backend.get_backend(META_INDEX)
+        }
+
+        /// Returns the analyzer configured for the index or the default analyzer.
+        pub fn analyzer(&self) -> $crate::analysis::Analyzer {
+            self.meta()
+                .and_then(|i| i.analyzer_config())
+                .map(|i| i.build())
+                .unwrap_or_default()
+        }
+
+        /// Returns the names of the posting lists of the index. The name at position `i` belongs
+        /// to posting list `i`.
+        #[inline]
+        pub fn posting_lists(&self) -> Vec<String> {
+            self.meta().map(|i| i.posting_lists()).unwrap_or_default()
+        }
+
         #[inline]
         pub fn flush(&mut self) -> crate::Result<()> {
             self.backend.flush()?;
@@ -216,15 +385,19 @@ macro_rules! ngram_index_functions {
 #[macro_export]
 macro_rules! ngram_index_mut_functions {
     ($storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
+        $crate::ngram_index_mut_functions!(NGramDict, $storage_impl, $postings_impl, $n, $s);
+    };
+    ($dict_impl:ident, $storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
         pub fn create(backend: B) -> crate::Result<Self>
         where
             B: GrowableBackend,
         {
             let mut mf = MultiFile::create(backend)?;
             // IMPORTANT: These must be in the same order as the index constants!
-            mf.insert_new_backend::<NGramDict<_, $n>>()?;
+            mf.insert_new_backend::<$dict_impl<_, $n>>()?;
             mf.insert_new_backend::<$storage_impl<_, $s>>()?;
             mf.insert_new_backend::<$postings_impl<_>>()?;
+            mf.insert_new_backend::<$crate::index::meta::IndexMeta<_>>()?;
             Ok(Self {
                 backend: mf,
                 p: std::marker::PhantomData,
@@ -242,12 +415,12 @@ macro_rules! ngram_index_mut_functions {
         }
 
         #[inline]
-        pub fn dict_mut(&mut self) -> NGramDict<MFileEntryMut<'_, B>, $n>
+        pub fn dict_mut(&mut self) -> $dict_impl<MFileEntryMut<'_, B>, $n>
         where
             B: GrowableBackend,
         {
             let entry = self.backend.entry_mut(DICT_INDEX).unwrap();
-            NGramDict::init(entry).unwrap()
+            $dict_impl::init(entry).unwrap()
         }
 
         #[inline]
@@ -267,13 +440,88 @@ macro_rules! ngram_index_mut_functions {
             let entry = self.backend.entry_mut(POSTINGS_INDEX).unwrap();
             $postings_impl::init(entry).unwrap()
         }
+
+        /// Returns the mutable metadata of the index. Indexes created without metadata get it
+        /// added.
+        pub fn meta_mut(
+            &mut self,
+        ) -> crate::Result<$crate::index::meta::IndexMeta<MFileEntryMut<'_, B>>>
+        where
+            B: GrowableBackend,
+        {
+            if !self.backend.has_id(META_INDEX) {
+                self.backend
+                    .insert_new_backend::<$crate::index::meta::IndexMeta<_>>()?;
+            }
+            let entry = self.backend.entry_mut(META_INDEX)?;
+            Ok($crate::index::meta::IndexMeta::init(entry)?)
+        }
+
+        /// Sets the analyzer config of the index. Editors and retrievers created afterwards use
+        /// the analyzer built from it for texts.
+        #[inline]
+        pub fn set_analyzer_config(
+            &mut self,
+            config: &$crate::analysis::config::AnalyzerConfig,
+        ) -> crate::Result<()>
+        where
+            B: GrowableBackend,
+        {
+            self.meta_mut()?.set_analyzer_config(config)
+        }
+
+        /// Names the posting lists of the index. The name at position `i` belongs to posting list
+        /// `i`. Document fields are named posting lists too. Existing names can't be changed or
+        /// removed, only new names can be appended.
+        pub fn set_posting_lists(&mut self, names: &[&str]) -> crate::Result<()>
+        where
+            B: GrowableBackend,
+        {
+            if names.len() > u16::MAX as usize {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            let mut meta = self.meta_mut()?;
+            let existing = meta.posting_lists();
+            if existing.len() > names.len() || existing.iter().zip(names).any(|(a, b)| a != b) {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            for (pos, name) in names.iter().enumerate() {
+                if names[..pos].contains(name) {
+                    return Err(crate::error::Error::DuplicateEntry);
+                }
+            }
+            let names: Vec<String> = names.iter().map(|i| i.to_string()).collect();
+            meta.set_posting_lists(&names)
+        }
+
+        /// Returns the ID of the posting list `name`, appending a new posting list if the index
+        /// doesn't have one with that name yet.
+        pub fn add_posting_list(&mut self, name: &str) -> crate::Result<u16>
+        where
+            B: GrowableBackend,
+        {
+            let mut meta = self.meta_mut()?;
+            let mut names = meta.posting_lists();
+            if let Some(pos) = names.iter().position(|i| i == name) {
+                return Ok(pos as u16);
+            }
+            if names.len() >= u16::MAX as usize {
+                return Err(crate::error::Error::UnsupportedOperation);
+            }
+            names.push(name.to_string());
+            meta.set_posting_lists(&names)?;
+            Ok(names.len() as u16 - 1)
+        }
     };
 }
 
 #[macro_export]
 macro_rules! implement_editable_ngindex_trait {
     ($storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
-        type DictImpl<'a> = NGramDict<MFileEntryMut<'a, B>, $n>
+        $crate::implement_editable_ngindex_trait!(NGramDict, $storage_impl, $postings_impl, $n, $s);
+    };
+    ($dict_impl:ident, $storage_impl:ident, $postings_impl:ident, $n:tt, $s:tt) => {
+        type DictImpl<'a> = $dict_impl<MFileEntryMut<'a, B>, $n>
                                                                                        where
                                                                                            B: 'a,
                                                                                            Self: 'a;
@@ -310,7 +558,10 @@ macro_rules! implement_editable_ngindex_trait {
 #[macro_export]
 macro_rules! implement_ngindex_dict_trait {
     ($n:tt) => {
-        type DictImpl<'a> = NGramDict<GeneralSubBackend<'a>, $n>
+        $crate::implement_ngindex_dict_trait!(NGramDict, $n);
+    };
+    ($dict_impl:ident, $n:tt) => {
+        type DictImpl<'a> = $dict_impl<GeneralSubBackend<'a>, $n>
                                                                                         where
                                                                                         B: 'a,
                                                                                         Self: 'a,
@@ -350,17 +601,38 @@ macro_rules! implement_ngindex_trait {
         fn get_postings(&self) -> Self::PostingsImpl<'_> {
             self.postings()
         }
+
+        #[inline]
+        fn get_meta(&self) -> Option<$crate::index::meta::IndexMeta<GeneralSubBackend<'_>>> {
+            self.meta()
+        }
     };
 }
 
 #[cfg(test)]
 pub mod test {
     use super::*;
-    use crate::edit::NewItem;
+    use crate::edit::{IndexEditor, NewItem};
+    use crate::index::dict::IndexDictionary;
     use crate::index::preset::DefaultIndex;
+    use crate::index::storage::IndexStorage;
+    use crate::index::traits::editable_index::EditableInvertedIndex;
+    use crate::index::traits::index::InvertedIndex;
+    use crate::retrieve::build::RetrieverBuilder;
+    use bytestore::backend::growable::GrowableBackend;
     use bytestore::backend::memory::{MemoryBackend, MemoryData};
     use bytestore::traits::creatable::Creatable;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
+
+    /// Queries over the terms of [`index_test_data`].
+    pub const TEST_QUERIES: [&[&str]; 6] = [
+        &["jotoba"],
+        &["a"],
+        &["jotoba", "of"],
+        &["a", "to"],
+        &["kanji", "to", "for"],
+        &["jotoba", "radical"],
+    ];
 
     pub fn index_test_data() -> (Vec<(Vec<String>, String)>, HashMap<String, Vec<String>>) {
         let text = r#"
@@ -405,6 +677,24 @@ pub mod test {
         (out, real_index)
     }
 
+    /// Inserts `data` with `editor`, putting every second item into posting list 1.
+    pub fn fill_index<E, B>(
+        mut editor: IndexEditor<E, B, String, String>,
+        data: &[(Vec<String>, String)],
+    ) where
+        B: GrowableBackend,
+        E: EditableInvertedIndex<B, String, String>,
+    {
+        for (p, (terms, storage_item)) in data.iter().enumerate() {
+            let post_id = p as u16 % 2;
+            let insert_item = NewItem::new(terms.clone(), storage_item.clone());
+            editor.insert_in_postings(insert_item, &[post_id]).unwrap();
+        }
+
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+    }
+
     pub fn make_index<B: GrowableBackend>(
         backend: B,
         data: &[(Vec<String>, String)],
@@ -419,16 +709,64 @@ pub mod test {
             editor = simple_index.editor().with_sorted_postings()
         }
 
-        for (p, (terms, storage_item)) in data.iter().enumerate() {
-            let post_id = p as u16 % 2;
-            let insert_item = NewItem::new(terms.clone(), storage_item.clone());
-            editor.insert_in_postings(insert_item, &[post_id]).unwrap();
-        }
+        fill_index(editor, data);
+        simple_index
+    }
 
-        editor.commit().unwrap();
-        editor.finish().unwrap();
+    /// Returns a builder for the terms of `query` in all posting lists of `index`.
+    pub fn query_builder<'a, I>(
+        index: &'a I,
+        query: &[&str],
+    ) -> RetrieverBuilder<'a, I::PostingsImpl<'a>, I>
+    where
+        I: InvertedIndex<String, String> + 'a,
+    {
+        let mut retrieve_builder = RetrieverBuilder::new(index);
+        for term in query.iter() {
+            let term_id = index.get_dict().term_id(&term.to_string()).unwrap();
+            retrieve_builder.add_term_id(term_id as u64);
+        }
+        retrieve_builder.in_all_postings();
+        retrieve_builder
+    }
 
-        simple_index
+    /// Returns the sorted items of `index` for the retrieved storage IDs.
+    pub fn retrieved_items<I, R>(index: &I, retriever: R) -> Vec<String>
+    where
+        I: InvertedIndex<String, String>,
+        R: Iterator<Item = u64>,
+    {
+        let mut res: Vec<_> = retriever
+            .map(|i| index.get_storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// Returns the sorted, unique sentences of `exp_index` that contain any term of `query` or
+    /// all of them if `all` is set.
+    pub fn expected(
+        exp_index: &HashMap<String, Vec<String>>,
+        query: &[&str],
+        all: bool,
+    ) -> Vec<String> {
+        let sets: Vec<HashSet<&String>> = query
+            .iter()
+            .map(|term| exp_index[*term].iter().collect())
+            .collect();
+
+        let mut exp: HashSet<&String> = sets[0].clone();
+        for set in sets.iter().skip(1) {
+            if all {
+                exp.retain(|i| set.contains(i));
+            } else {
+                exp.extend(set.iter());
+            }
+        }
+
+        let mut exp: Vec<String> = exp.into_iter().cloned().collect();
+        exp.sort_unstable();
+        exp
     }
 
     pub fn sorted_test_index() -> DefaultIndex<MemoryBackend, String, String> {
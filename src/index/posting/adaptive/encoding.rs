@@ -0,0 +1,234 @@
+use crate::index::posting::bitmap::{decode_bitmap, encode_bitmap};
+use roaring::treemap::IntoIter;
+use roaring::RoaringTreemap;
+
+/// Size of the header of a single posting: The encoding tag (u8) and the amount of storage IDs
+/// (u32).
+const HEADER_SIZE: usize = 5;
+
+/// The encoding of a single term's storage IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PostingEncoding {
+    /// Plain little endian u64 values.
+    Raw = 0,
+    /// Sorted, delta encoded varints.
+    Varint = 1,
+    /// A serialized roaring bitmap.
+    Bitmap = 2,
+}
+
+impl TryFrom<u8> for PostingEncoding {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Raw,
+            1 => Self::Varint,
+            2 => Self::Bitmap,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Encodes sorted and deduplicated storage IDs with the encoding that needs the least space.
+pub(crate) fn encode(ids: &[u64]) -> Vec<u8> {
+    let len: u32 = ids.len().try_into().expect("Posting too large");
+
+    let raw_size = ids.len() * 8;
+    let varint_size = varint_size(ids);
+    let bitmap = RoaringTreemap::from_sorted_iter(ids.iter().copied()).unwrap();
+    let bitmap_size = bitmap.serialized_size();
+
+    let encoding = if bitmap_size < varint_size.min(raw_size) {
+        PostingEncoding::Bitmap
+    } else if varint_size < raw_size {
+        PostingEncoding::Varint
+    } else {
+        PostingEncoding::Raw
+    };
+
+    let mut out = Vec::with_capacity(HEADER_SIZE + raw_size.min(varint_size).min(bitmap_size));
+    out.push(encoding as u8);
+    out.extend_from_slice(&len.to_le_bytes());
+
+    match encoding {
+        PostingEncoding::Raw => {
+            for id in ids {
+                out.extend_from_slice(&id.to_le_bytes());
+            }
+        }
+        PostingEncoding::Varint => {
+            let mut prev = 0;
+            for id in ids {
+                let (enc, l) = varint_simd::encode(*id - prev);
+                out.extend_from_slice(&enc[..l as usize]);
+                prev = *id;
+            }
+        }
+        PostingEncoding::Bitmap => out.extend(encode_bitmap(&bitmap)),
+    }
+
+    out
+}
+
+/// Returns the amount of bytes needed to store the given IDs as delta encoded varints.
+fn varint_size(ids: &[u64]) -> usize {
+    let mut prev = 0;
+    ids.iter()
+        .map(|id| {
+            let len = varint_simd::encode(*id - prev).1 as usize;
+            prev = *id;
+            len
+        })
+        .sum()
+}
+
+/// Decodes all storage IDs of an encoded posting.
+#[inline]
+pub(crate) fn decode(data: &[u8]) -> Vec<u64> {
+    AdaptivePostingIter::new(data).collect()
+}
+
+/// Decodes an encoded posting into a bitmap.
+pub(crate) fn decode_to_bitmap(data: &[u8]) -> RoaringTreemap {
+    if encoding_of(data) == Some(PostingEncoding::Bitmap) {
+        return decode_bitmap(&data[HEADER_SIZE..]);
+    }
+    RoaringTreemap::from_sorted_iter(AdaptivePostingIter::new(data)).unwrap()
+}
+
+/// Returns the encoding of an encoded posting or `None` if the posting is empty.
+#[inline]
+pub(crate) fn encoding_of(data: &[u8]) -> Option<PostingEncoding> {
+    PostingEncoding::try_from(*data.first()?).ok()
+}
+
+/// Returns the amount of storage IDs stored in the header of an encoded posting.
+#[inline]
+pub(crate) fn posting_len(data: &[u8]) -> usize {
+    if data.len() < HEADER_SIZE {
+        return 0;
+    }
+    u32::from_le_bytes(data[1..HEADER_SIZE].try_into().unwrap()) as usize
+}
+
+/// Iterator over the storage IDs of a posting that dispatches to the posting's encoding.
+pub enum AdaptivePostingIter<'a> {
+    Raw(RawIter<'a>),
+    Varint(VarintIter<'a>),
+    Bitmap(IntoIter),
+    Empty,
+}
+
+impl<'a> AdaptivePostingIter<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        let Some(encoding) = encoding_of(data) else {
+            return Self::Empty;
+        };
+
+        let len = posting_len(data);
+        let data = &data[HEADER_SIZE..];
+
+        match encoding {
+            PostingEncoding::Raw => Self::Raw(RawIter { data, pos: 0, len }),
+            PostingEncoding::Varint => Self::Varint(VarintIter {
+                data,
+                pos: 0,
+                left: len,
+                prev: 0,
+            }),
+            PostingEncoding::Bitmap => Self::Bitmap(decode_bitmap(data).into_iter()),
+        }
+    }
+}
+
+impl<'a> Iterator for AdaptivePostingIter<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            AdaptivePostingIter::Raw(i) => i.next(),
+            AdaptivePostingIter::Varint(i) => i.next(),
+            AdaptivePostingIter::Bitmap(i) => i.next(),
+            AdaptivePostingIter::Empty => None,
+        }
+    }
+}
+
+/// Iterator over raw encoded storage IDs.
+pub struct RawIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> Iterator for RawIter<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let start = self.pos * 8;
+        self.pos += 1;
+        Some(u64::from_le_bytes(
+            self.data[start..start + 8].try_into().unwrap(),
+        ))
+    }
+}
+
+/// Iterator over delta encoded varint storage IDs.
+pub struct VarintIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    left: usize,
+    prev: u64,
+}
+
+impl<'a> Iterator for VarintIter<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        let (delta, l) =
+            varint_simd::decode::<u64>(&self.data[self.pos..]).expect("Invalid posting data");
+        self.pos += l;
+        self.left -= 1;
+        self.prev += delta;
+        Some(self.prev)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(ids: Vec<u64>, exp_encoding: PostingEncoding) {
+        let encoded = encode(&ids);
+        assert_eq!(encoding_of(&encoded), Some(exp_encoding));
+        assert_eq!(decode(&encoded), ids);
+        assert_eq!(posting_len(&encoded), ids.len());
+        assert!(decode_to_bitmap(&encoded).iter().eq(ids.iter().copied()));
+
+        // Trailing bytes of previously larger postings must be ignored.
+        let mut padded = encoded.clone();
+        padded.extend([0xff; 20]);
+        assert_eq!(decode(&padded), ids);
+    }
+
+    #[test]
+    fn adaptive_encoding() {
+        check(vec![5], PostingEncoding::Varint);
+        check((0..1000).map(|i| i * 3).collect(), PostingEncoding::Varint);
+        check((0..100_000).collect(), PostingEncoding::Bitmap);
+        check(vec![u64::MAX / 3, (u64::MAX / 3) * 2], PostingEncoding::Raw);
+        assert_eq!(decode(&[]), Vec::<u64>::new());
+    }
+}
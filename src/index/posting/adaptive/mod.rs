@@ -0,0 +1,220 @@
+pub mod editor;
+pub mod encoding;
+
+use crate::error::Error;
+use crate::index::posting::bitmap::{intersection_bitmap, union_bitmap};
+use crate::index::posting::{
+    count, BitmapPosting, CountablePosting, EditableIndexPosting, IndexPosting,
+};
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+use editor::AdaptivePostingEditor;
+use encoding::{AdaptivePostingIter, PostingEncoding};
+use roaring::RoaringTreemap;
+
+/// Postings that choose the smallest encoding for each term separately when committing. Terms can
+/// be stored as raw u64 values, delta encoded varints or roaring bitmaps. The chosen encoding is
+/// stored as tag in front of each term's postings.
+pub struct AdaptivePostings<B> {
+    pub postings: MultiFile<B>,
+}
+
+impl<B> Creatable<B> for AdaptivePostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let postings = MultiFile::with_capacity(backend, capacity)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> Initiable<B> for AdaptivePostings<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let postings = MultiFile::init(backend)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> AdaptivePostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn posting_list_mut(
+        &mut self,
+        posting_id: usize,
+    ) -> Result<IndexedFile<MFileEntryMut<B>>, Error> {
+        if !self.postings.has_id(posting_id) {
+            let needed = posting_id - self.postings.count();
+            for _ in 0..=needed {
+                self.postings
+                    .insert_new_backend::<IndexedFile<_>>()
+                    .unwrap();
+            }
+        }
+        let backend = self
+            .postings
+            .get_backend_mut::<IndexedFile<_>>(posting_id)
+            .unwrap();
+        Ok(backend)
+    }
+}
+
+impl<B> EditableIndexPosting for AdaptivePostings<B>
+where
+    B: GrowableBackend,
+{
+    type Editor<'a> = AdaptivePostingEditor<'a, B> where Self: 'a, B: 'a;
+
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        AdaptivePostingEditor::new(self)
+    }
+}
+
+impl<B> AdaptivePostings<B>
+where
+    B: Backend,
+{
+    /// Returns the amount of posting lists.
+    #[inline]
+    pub fn posting_list_count(&self) -> usize {
+        self.postings.count()
+    }
+
+    /// Returns the encoding that was chosen for the given term.
+    #[inline]
+    pub fn posting_encoding(&self, post_id: usize, term_id: u64) -> Option<PostingEncoding> {
+        encoding::encoding_of(self.posting_data(post_id, term_id)?)
+    }
+
+    #[inline]
+    fn posting_data<'a>(&self, post_id: usize, term_id: u64) -> Option<&'a [u8]> {
+        let ifile: IndexedFile<_> = self.postings.get_backend(post_id)?;
+        let data = ifile.get(term_id.try_into().ok()?).ok()?;
+
+        // Safety:
+        // The actual lifetime of the data is bound to <B> which &self is also bound to.
+        Some(unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) })
+    }
+}
+
+impl<B> BitmapPosting for AdaptivePostings<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn posting_bitmap(&self, post_id: usize, term_id: u64) -> Option<RoaringTreemap> {
+        let data = self.posting_data(post_id, term_id)?;
+        Some(encoding::decode_to_bitmap(data))
+    }
+}
+
+impl<B> IndexPosting for AdaptivePostings<B>
+where
+    B: Backend,
+{
+    type PostingRetriever<'a> = AdaptivePostingIter<'a> where Self: 'a;
+
+    #[inline]
+    fn posting_retriever(
+        &self,
+        post_id: usize,
+        term_id: u64,
+    ) -> Option<Self::PostingRetriever<'_>> {
+        let data = self.posting_data(post_id, term_id)?;
+        Some(AdaptivePostingIter::new(data))
+    }
+
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        self.posting_data(post_id, term_id)
+            .map(encoding::posting_len)
+            .unwrap_or(0)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.postings.count()
+    }
+}
+
+impl<B> CountablePosting for AdaptivePostings<B>
+where
+    B: Backend,
+{
+    const UNIQUE_IDS: bool = true;
+
+    #[inline]
+    fn count_union(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| union_bitmap(self, posting_lists, term_ids).len() as usize)
+    }
+
+    #[inline]
+    fn count_intersection(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| intersection_bitmap(self, posting_lists, term_ids).len() as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::posting::IndexPostingEditor;
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn posting() {
+        let mut postings = AdaptivePostings::create_mem_with_capacity(10).unwrap();
+
+        let mut editor = postings.editor();
+        editor.announce_term_count(2).unwrap();
+        editor.insert_posts(0, 4, &[0, 1]).unwrap();
+        editor.insert_posts(0, 2, &[1]).unwrap();
+        editor.commit().unwrap();
+
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(
+            postings.posting_encoding(0, 1),
+            Some(PostingEncoding::Varint)
+        );
+
+        // Term 1 becomes dense after the second commit and should be switched to a bitmap while
+        // term 0 stays sparse.
+        let mut editor = postings.editor();
+        for i in 0..100_000 {
+            editor.insert_posts(0, i, &[1]).unwrap();
+        }
+        editor.insert_posts(0, 1_000_000, &[0]).unwrap();
+        editor.commit().unwrap();
+
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert!(r.eq(0..100_000));
+        assert_eq!(postings.posting_len(0, 1), 100_000);
+        assert_eq!(
+            postings.posting_encoding(0, 1),
+            Some(PostingEncoding::Bitmap)
+        );
+
+        let r = postings.posting_retriever(0, 0).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![4, 1_000_000]);
+        assert_eq!(
+            postings.posting_encoding(0, 0),
+            Some(PostingEncoding::Varint)
+        );
+    }
+}
@@ -0,0 +1,307 @@
+pub mod editor;
+
+use crate::error::Error;
+use crate::index::posting::{
+    count, BitmapPosting, CountablePosting, EditableIndexPosting, IndexPosting,
+};
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+use editor::RoaringPostingEditor;
+use roaring::treemap::IntoIter;
+use roaring::RoaringTreemap;
+
+/// Size of the cardinality header in front of each serialized bitmap.
+pub(crate) const COUNT_SIZE: usize = 8;
+
+/// Postings that store the storage IDs of each term as serialized roaring bitmap. This is a lot
+/// smaller than varints for terms that are assigned to a large share of all storage items and
+/// allows retrievers to use fast bitmap operations.
+///
+/// Each term's bitmap is prefixed with its cardinality (u64) so the length of a posting can be
+/// looked up without deserializing the bitmap.
+pub struct RoaringPostings<B> {
+    pub postings: MultiFile<B>,
+}
+
+impl<B> Creatable<B> for RoaringPostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let postings = MultiFile::with_capacity(backend, capacity)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> Initiable<B> for RoaringPostings<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let postings = MultiFile::init(backend)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> RoaringPostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn posting_list_mut(
+        &mut self,
+        posting_id: usize,
+    ) -> Result<IndexedFile<MFileEntryMut<B>>, Error> {
+        if !self.postings.has_id(posting_id) {
+            let needed = posting_id - self.postings.count();
+            for _ in 0..=needed {
+                self.postings
+                    .insert_new_backend::<IndexedFile<_>>()
+                    .unwrap();
+            }
+        }
+        let backend = self
+            .postings
+            .get_backend_mut::<IndexedFile<_>>(posting_id)
+            .unwrap();
+        Ok(backend)
+    }
+}
+
+impl<B> EditableIndexPosting for RoaringPostings<B>
+where
+    B: GrowableBackend,
+{
+    type Editor<'a> = RoaringPostingEditor<'a, B> where Self: 'a, B: 'a;
+
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        RoaringPostingEditor::new(self)
+    }
+}
+
+impl<B> RoaringPostings<B>
+where
+    B: Backend,
+{
+    /// Returns the amount of posting lists.
+    #[inline]
+    pub fn posting_list_count(&self) -> usize {
+        self.postings.count()
+    }
+
+    #[inline]
+    fn posting_data(&self, post_id: usize, term_id: u64) -> Option<&[u8]> {
+        let ifile: IndexedFile<_> = self.postings.get_backend(post_id)?;
+        let data = ifile.get(term_id.try_into().ok()?).ok()?;
+
+        // Safety:
+        // The actual lifetime of the data is bound to <B> which &self is also bound to.
+        Some(unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) })
+    }
+}
+
+impl<B> BitmapPosting for RoaringPostings<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn posting_bitmap(&self, post_id: usize, term_id: u64) -> Option<RoaringTreemap> {
+        let data = self.posting_data(post_id, term_id)?;
+        Some(decode_posting(data))
+    }
+}
+
+impl<B> IndexPosting for RoaringPostings<B>
+where
+    B: Backend,
+{
+    type PostingRetriever<'a> = IntoIter where Self: 'a;
+
+    #[inline]
+    fn posting_retriever(
+        &self,
+        post_id: usize,
+        term_id: u64,
+    ) -> Option<Self::PostingRetriever<'_>> {
+        Some(self.posting_bitmap(post_id, term_id)?.into_iter())
+    }
+
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        self.posting_data(post_id, term_id)
+            .map(posting_len)
+            .unwrap_or(0)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.postings.count()
+    }
+}
+
+impl<B> CountablePosting for RoaringPostings<B>
+where
+    B: Backend,
+{
+    const UNIQUE_IDS: bool = true;
+
+    #[inline]
+    fn count_union(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| union_bitmap(self, posting_lists, term_ids).len() as usize)
+    }
+
+    #[inline]
+    fn count_intersection(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| intersection_bitmap(self, posting_lists, term_ids).len() as usize)
+    }
+}
+
+/// Returns the union of a terms bitmaps over all requested posting lists.
+fn term_bitmap<P: BitmapPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_id: u64,
+) -> RoaringTreemap {
+    let mut bitmap = RoaringTreemap::new();
+    for posting_id in posting_lists.iter() {
+        if let Some(b) = postings.posting_bitmap(*posting_id as usize, term_id) {
+            bitmap |= b;
+        }
+    }
+    bitmap
+}
+
+/// Returns the union of all terms bitmaps.
+pub(crate) fn union_bitmap<P: BitmapPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> RoaringTreemap {
+    let mut bitmap = RoaringTreemap::new();
+    for term_id in term_ids.iter() {
+        bitmap |= term_bitmap(postings, posting_lists, *term_id);
+    }
+    bitmap
+}
+
+/// Returns the intersection of all terms bitmaps.
+pub(crate) fn intersection_bitmap<P: BitmapPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> RoaringTreemap {
+    let mut terms = term_ids.iter();
+    let Some(first) = terms.next() else {
+        return RoaringTreemap::new();
+    };
+
+    let mut bitmap = term_bitmap(postings, posting_lists, *first);
+    for term_id in terms {
+        if bitmap.is_empty() {
+            break;
+        }
+        bitmap &= term_bitmap(postings, posting_lists, *term_id);
+    }
+    bitmap
+}
+
+/// Decodes a serialized bitmap. Terms without any postings have no data and return an empty bitmap.
+#[inline]
+pub(crate) fn decode_bitmap(data: &[u8]) -> RoaringTreemap {
+    if data.is_empty() {
+        return RoaringTreemap::new();
+    }
+    RoaringTreemap::deserialize_from(data).expect("Invalid posting data")
+}
+
+#[inline]
+pub(crate) fn encode_bitmap(bitmap: &RoaringTreemap) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bitmap.serialized_size());
+    bitmap
+        .serialize_into(&mut out)
+        .expect("Writing into a Vec can't fail");
+    out
+}
+
+/// Returns the cardinality stored in front of a terms bitmap.
+#[inline]
+pub(crate) fn posting_len(data: &[u8]) -> usize {
+    if data.len() < COUNT_SIZE {
+        return 0;
+    }
+    u64::from_le_bytes(data[..COUNT_SIZE].try_into().unwrap()) as usize
+}
+
+/// Decodes a terms posting consisting of the cardinality header and the serialized bitmap.
+#[inline]
+pub(crate) fn decode_posting(data: &[u8]) -> RoaringTreemap {
+    if data.len() < COUNT_SIZE {
+        return RoaringTreemap::new();
+    }
+    decode_bitmap(&data[COUNT_SIZE..])
+}
+
+/// Encodes a bitmap prefixed with its cardinality.
+#[inline]
+pub(crate) fn encode_posting(bitmap: &RoaringTreemap) -> Vec<u8> {
+    let mut out = Vec::with_capacity(COUNT_SIZE + bitmap.serialized_size());
+    out.extend_from_slice(&bitmap.len().to_le_bytes());
+    bitmap
+        .serialize_into(&mut out)
+        .expect("Writing into a Vec can't fail");
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::posting::IndexPostingEditor;
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn posting() {
+        let mut postings = RoaringPostings::create_mem_with_capacity(10).unwrap();
+
+        let mut editor = postings.editor();
+        editor.announce_term_count(2).unwrap();
+        editor.insert_posts(0, 4, &[0, 1]).unwrap();
+        editor.insert_posts(0, 2, &[1]).unwrap();
+        editor.commit().unwrap();
+
+        let r = postings.posting_retriever(0, 0).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![4]);
+
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(postings.posting_len(0, 1), 2);
+
+        // Second commit gets merged into the existing bitmaps.
+        let mut editor = postings.editor();
+        for i in 0..100_000 {
+            editor.insert_posts(0, i, &[1]).unwrap();
+        }
+        editor.insert_posts(2, u64::MAX, &[3]).unwrap();
+        editor.commit().unwrap();
+
+        let bitmap = postings.posting_bitmap(0, 1).unwrap();
+        assert_eq!(bitmap.len(), 100_000);
+        assert_eq!(postings.posting_len(0, 1), 100_000);
+        assert_eq!(postings.posting_len(0, 2), 0);
+        assert!(bitmap.iter().eq(0..100_000));
+
+        let r = postings.posting_retriever(2, 3).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![u64::MAX]);
+        assert_eq!(postings.posting_list_count(), 3);
+    }
+}
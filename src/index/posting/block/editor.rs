@@ -0,0 +1,103 @@
+use super::{decode_blocks, encode_blocks, BlockPostings};
+use crate::index::entry::overwrite_entries;
+use crate::index::posting::IndexPostingEditor;
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use std::collections::HashMap;
+
+pub struct BlockPostingEditor<'a, B> {
+    postings: &'a mut BlockPostings<B>,
+
+    /// All pending insertions. Maps term_ids to its storage IDs.
+    pending: Vec<HashMap<usize, Vec<u64>>>,
+}
+
+impl<'a, B> BlockPostingEditor<'a, B> {
+    #[inline]
+    pub(super) fn new(postings: &'a mut BlockPostings<B>) -> Self {
+        Self {
+            postings,
+            pending: vec![],
+        }
+    }
+}
+
+impl<'a, B> BlockPostingEditor<'a, B>
+where
+    B: GrowableBackend,
+{
+    /// Merges the new storage IDs with the existing ones and re-encodes the blocks of all affected
+    /// terms.
+    pub fn commit_postings(
+        &mut self,
+        post_id: usize,
+        postings: HashMap<usize, Vec<u64>>,
+    ) -> Result<()> {
+        let mut posting_list = self.postings.posting_list_mut(post_id)?;
+
+        let mut encoded = Vec::with_capacity(postings.len());
+        for (term_id, mut storage_ids) in postings {
+            if let Ok(existing) = posting_list.get(term_id) {
+                storage_ids.extend(decode_blocks(existing));
+            }
+            storage_ids.sort_unstable();
+            storage_ids.dedup();
+            encoded.push((term_id, encode_blocks(&storage_ids)));
+        }
+
+        overwrite_entries(&mut posting_list, encoded)
+    }
+}
+
+impl<'a, B> IndexPostingEditor for BlockPostingEditor<'a, B>
+where
+    B: GrowableBackend,
+{
+    fn announce_term_count(&mut self, count: usize) -> Result<()> {
+        self.pending
+            .resize_with(count, || HashMap::with_capacity(1));
+        Ok(())
+    }
+
+    fn insert_posts(&mut self, post_id: u16, storage_id: u64, term_ids: &[usize]) -> Result<()> {
+        if term_ids.is_empty() {
+            return Ok(());
+        }
+
+        let post_id = post_id as usize;
+        if post_id >= self.pending.len() {
+            self.pending.resize_with(post_id + 1, HashMap::default);
+        }
+
+        // We can unwrap here since we checked the availability of `post_id` a few lines before.
+        let post = self.pending.get_mut(post_id).unwrap();
+
+        for term_id in term_ids.iter() {
+            post.entry(*term_id).or_default().push(storage_id);
+        }
+
+        Ok(())
+    }
+
+    /// Block postings are always sorted so this is a no-op.
+    #[inline]
+    fn sort_postings(&mut self, _posting_id: usize, _term_id: usize) -> Result<()> {
+        Ok(())
+    }
+
+    /// Block postings are always sorted so this is a no-op.
+    #[inline]
+    fn sort_all_postings(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn commit(mut self) -> Result<()> {
+        let pending = std::mem::take(&mut self.pending);
+        for (post_id, new_mappings) in pending.into_iter().enumerate() {
+            if !new_mappings.is_empty() {
+                self.commit_postings(post_id, new_mappings)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use super::{BlockHeader, BLOCK_SIZE};
+use crate::index::posting::SeekablePosting;
+
+/// Iterator over a block encoded posting. Only the block containing the current storage ID is
+/// decoded, so [`SeekablePosting::seek`] can skip whole blocks using the block headers.
+pub struct BlockPostingIter<'a> {
+    header: BlockHeader<'a>,
+
+    /// Index of the next block that will be decoded.
+    next_block: usize,
+
+    /// Currently decoded block.
+    block: Vec<u64>,
+    pos: usize,
+}
+
+impl<'a> BlockPostingIter<'a> {
+    #[inline]
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            header: BlockHeader::new(data),
+            next_block: 0,
+            block: Vec::with_capacity(BLOCK_SIZE),
+            pos: 0,
+        }
+    }
+
+    /// Returns the total amount of storage IDs in the posting.
+    #[inline]
+    pub fn posting_len(&self) -> usize {
+        self.header.len()
+    }
+
+    /// Decodes the next block into the internal buffer. Returns `false` if there are no blocks
+    /// left.
+    fn load_next_block(&mut self) -> bool {
+        if self.next_block >= self.header.block_count() {
+            return false;
+        }
+        self.block.clear();
+        self.header.decode_block(self.next_block, &mut self.block);
+        self.next_block += 1;
+        self.pos = 0;
+        true
+    }
+
+    /// Returns the index of the first not yet decoded block that can contain `target`.
+    fn find_block(&self, target: u64) -> usize {
+        let mut left = self.next_block;
+        let mut right = self.header.block_count();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if self.header.block_max(mid) < target {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+        left
+    }
+}
+
+impl<'a> Iterator for BlockPostingIter<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.block.len() && !self.load_next_block() {
+            return None;
+        }
+        let item = self.block[self.pos];
+        self.pos += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let decoded = (self.next_block * BLOCK_SIZE).min(self.header.len());
+        let left = self.header.len() - decoded + (self.block.len() - self.pos);
+        (left, Some(left))
+    }
+}
+
+impl<'a> SeekablePosting for BlockPostingIter<'a> {
+    fn seek(&mut self, target: u64) -> Option<u64> {
+        let in_curr_block =
+            self.pos < self.block.len() && self.block[self.block.len() - 1] >= target;
+
+        if !in_curr_block {
+            self.next_block = self.find_block(target);
+            if !self.load_next_block() {
+                self.pos = self.block.len();
+                return None;
+            }
+        }
+
+        // The block's max ID is >= target so there is always an item that matches.
+        self.pos += self.block[self.pos..].partition_point(|i| *i < target);
+        self.next()
+    }
+}
@@ -0,0 +1,352 @@
+pub mod editor;
+pub mod iter;
+
+use crate::error::Error;
+use crate::index::posting::{count, seek, CountablePosting, EditableIndexPosting, IndexPosting};
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::initiable::Initiable;
+use editor::BlockPostingEditor;
+use iter::BlockPostingIter;
+
+/// Amount of storage IDs encoded in a single block.
+pub const BLOCK_SIZE: usize = 128;
+
+/// Size of the header of a single posting: The amount of storage IDs and blocks, both as u32.
+const HEADER_SIZE: usize = 8;
+
+/// Size of a single block header: The max storage ID (u64) and the blocks data offset (u32).
+const BLOCK_HEADER_SIZE: usize = 12;
+
+/// Postings that store the storage IDs of each term sorted in blocks of [`BLOCK_SIZE`] delta
+/// encoded varints. Each block has a header with its max storage ID and data offset which allows
+/// skipping whole blocks when seeking to a storage ID.
+///
+/// Layout of a single posting:
+/// `[len: u32][block count: u32][(max id: u64, offset: u32); block count][block data]`
+pub struct BlockPostings<B> {
+    pub postings: MultiFile<B>,
+}
+
+impl<B> Creatable<B> for BlockPostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let postings = MultiFile::with_capacity(backend, capacity)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> Initiable<B> for BlockPostings<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let postings = MultiFile::init(backend)?;
+        Ok(Self { postings })
+    }
+}
+
+impl<B> BlockPostings<B>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn posting_list_mut(
+        &mut self,
+        posting_id: usize,
+    ) -> Result<IndexedFile<MFileEntryMut<B>>, Error> {
+        if !self.postings.has_id(posting_id) {
+            let needed = posting_id - self.postings.count();
+            for _ in 0..=needed {
+                self.postings
+                    .insert_new_backend::<IndexedFile<_>>()
+                    .unwrap();
+            }
+        }
+        let backend = self
+            .postings
+            .get_backend_mut::<IndexedFile<_>>(posting_id)
+            .unwrap();
+        Ok(backend)
+    }
+}
+
+impl<B> EditableIndexPosting for BlockPostings<B>
+where
+    B: GrowableBackend,
+{
+    type Editor<'a> = BlockPostingEditor<'a, B> where Self: 'a, B: 'a;
+
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        BlockPostingEditor::new(self)
+    }
+}
+
+impl<B> BlockPostings<B>
+where
+    B: Backend,
+{
+    /// Returns the amount of posting lists.
+    #[inline]
+    pub fn posting_list_count(&self) -> usize {
+        self.postings.count()
+    }
+
+    #[inline]
+    fn posting_data<'a>(&self, post_id: usize, term_id: u64) -> Option<&'a [u8]> {
+        let ifile: IndexedFile<_> = self.postings.get_backend(post_id)?;
+        let data = ifile.get(term_id.try_into().ok()?).ok()?;
+
+        // Safety:
+        // The actual lifetime of the data is bound to <B> which &self is also bound to.
+        Some(unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) })
+    }
+}
+
+impl<B> IndexPosting for BlockPostings<B>
+where
+    B: Backend,
+{
+    type PostingRetriever<'a> = BlockPostingIter<'a> where Self: 'a;
+
+    #[inline]
+    fn posting_retriever(
+        &self,
+        post_id: usize,
+        term_id: u64,
+    ) -> Option<Self::PostingRetriever<'_>> {
+        let data = self.posting_data(post_id, term_id)?;
+        Some(BlockPostingIter::new(data))
+    }
+
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        self.posting_data(post_id, term_id)
+            .map(|data| BlockHeader::new(data).len())
+            .unwrap_or(0)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.postings.count()
+    }
+}
+
+impl<B> CountablePosting for BlockPostings<B>
+where
+    B: Backend,
+{
+    const UNIQUE_IDS: bool = true;
+
+    /// Postings are always sorted, so the counting skips over whole blocks instead of collecting
+    /// the storage IDs.
+    #[inline]
+    fn count_union(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids).unwrap_or_else(|| {
+            seek::count_union(seek::term_cursors(self, posting_lists, term_ids, |i| i))
+        })
+    }
+
+    #[inline]
+    fn count_intersection(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids).unwrap_or_else(|| {
+            seek::count_intersection(seek::term_cursors(self, posting_lists, term_ids, |i| i))
+        })
+    }
+
+}
+
+/// Encodes sorted and deduplicated storage IDs into a block encoded posting.
+pub(crate) fn encode_blocks(ids: &[u64]) -> Vec<u8> {
+    let len: u32 = ids.len().try_into().expect("Posting too large");
+    let block_count = ids.len().div_ceil(BLOCK_SIZE);
+
+    let mut out = Vec::with_capacity(HEADER_SIZE + block_count * BLOCK_HEADER_SIZE + ids.len());
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&(block_count as u32).to_le_bytes());
+
+    let headers_start = out.len();
+    out.resize(headers_start + block_count * BLOCK_HEADER_SIZE, 0);
+    let data_start = out.len();
+
+    let mut prev = 0;
+    for (block, chunk) in ids.chunks(BLOCK_SIZE).enumerate() {
+        let offset = (out.len() - data_start) as u32;
+
+        for id in chunk {
+            let (enc, l) = varint_simd::encode(*id - prev);
+            out.extend_from_slice(&enc[..l as usize]);
+            prev = *id;
+        }
+
+        let header = headers_start + block * BLOCK_HEADER_SIZE;
+        out[header..header + 8].copy_from_slice(&prev.to_le_bytes());
+        out[header + 8..header + BLOCK_HEADER_SIZE].copy_from_slice(&offset.to_le_bytes());
+    }
+
+    out
+}
+
+/// Decodes all storage IDs of a block encoded posting.
+pub(crate) fn decode_blocks(data: &[u8]) -> Vec<u64> {
+    let header = BlockHeader::new(data);
+    let mut out = Vec::with_capacity(header.len());
+    for block in 0..header.block_count() {
+        header.decode_block(block, &mut out);
+    }
+    out
+}
+
+/// Read access to the header and blocks of an encoded posting.
+pub(crate) struct BlockHeader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BlockHeader<'a> {
+    #[inline]
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the amount of storage IDs in the posting. Terms without any postings have no data.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        if self.data.len() < HEADER_SIZE {
+            return 0;
+        }
+        self.read_u32(0) as usize
+    }
+
+    #[inline]
+    pub(crate) fn block_count(&self) -> usize {
+        if self.data.len() < HEADER_SIZE {
+            return 0;
+        }
+        self.read_u32(4) as usize
+    }
+
+    /// Returns the highest storage ID in the given block.
+    #[inline]
+    pub(crate) fn block_max(&self, block: usize) -> u64 {
+        let pos = HEADER_SIZE + block * BLOCK_HEADER_SIZE;
+        u64::from_le_bytes(self.data[pos..pos + 8].try_into().unwrap())
+    }
+
+    #[inline]
+    fn block_offset(&self, block: usize) -> usize {
+        let pos = HEADER_SIZE + block * BLOCK_HEADER_SIZE + 8;
+        self.read_u32(pos) as usize
+    }
+
+    #[inline]
+    fn block_len(&self, block: usize) -> usize {
+        (self.len() - block * BLOCK_SIZE).min(BLOCK_SIZE)
+    }
+
+    /// Decodes all storage IDs of `block` and appends them to `out`.
+    pub(crate) fn decode_block(&self, block: usize, out: &mut Vec<u64>) {
+        let data_start = HEADER_SIZE + self.block_count() * BLOCK_HEADER_SIZE;
+        let mut pos = data_start + self.block_offset(block);
+        let mut prev = if block == 0 {
+            0
+        } else {
+            self.block_max(block - 1)
+        };
+
+        for _ in 0..self.block_len(block) {
+            let (delta, l) =
+                varint_simd::decode::<u64>(&self.data[pos..]).expect("Invalid posting data");
+            pos += l;
+            prev += delta;
+            out.push(prev);
+        }
+    }
+
+    #[inline]
+    fn read_u32(&self, pos: usize) -> u32 {
+        u32::from_le_bytes(self.data[pos..pos + 4].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::posting::{IndexPostingEditor, SeekablePosting};
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn encode_decode() {
+        for len in [0, 1, 127, 128, 129, 1000] {
+            let ids: Vec<u64> = (0..len).map(|i| i * 3 + (i % 7)).collect();
+            let encoded = encode_blocks(&ids);
+            assert_eq!(decode_blocks(&encoded), ids);
+            assert_eq!(BlockPostingIter::new(&encoded).collect::<Vec<_>>(), ids);
+        }
+    }
+
+    #[test]
+    fn seek() {
+        let ids: Vec<u64> = (0..10_000).map(|i| i * 2).collect();
+        let encoded = encode_blocks(&ids);
+
+        let mut iter = BlockPostingIter::new(&encoded);
+        assert_eq!(iter.seek(0), Some(0));
+        assert_eq!(iter.seek(1), Some(2));
+        assert_eq!(iter.seek(1001), Some(1002));
+        assert_eq!(iter.next(), Some(1004));
+        // Seeking never goes backwards.
+        assert_eq!(iter.seek(10), Some(1006));
+        assert_eq!(iter.seek(19_998), Some(19_998));
+        assert_eq!(iter.seek(19_999), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn posting() {
+        let mut postings = BlockPostings::create_mem_with_capacity(10).unwrap();
+
+        let mut editor = postings.editor();
+        editor.announce_term_count(2).unwrap();
+        editor.insert_posts(0, 5, &[0, 1]).unwrap();
+        editor.insert_posts(0, 1, &[1]).unwrap();
+        editor.commit().unwrap();
+
+        let r = postings.posting_retriever(0, 0).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![5]);
+
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![1, 5]);
+
+        // Postings of a second commit get merged into the existing blocks.
+        let mut editor = postings.editor();
+        for i in 0..500 {
+            editor.insert_posts(0, i * 10, &[1]).unwrap();
+        }
+        editor.insert_posts(1, 3, &[2]).unwrap();
+        editor.commit().unwrap();
+
+        let mut exp: Vec<u64> = (0..500).map(|i| i * 10).collect();
+        exp.extend([1, 5]);
+        exp.sort_unstable();
+        exp.dedup();
+
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), exp);
+
+        let r = postings.posting_retriever(1, 2).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![3]);
+        assert_eq!(postings.posting_len(0, 1), exp.len());
+        assert_eq!(postings.posting_len(0, 0), 1);
+        assert_eq!(postings.posting_list_count(), 2);
+    }
+}
@@ -1,12 +1,13 @@
 use crate::error::Error;
-use crate::index::posting::compressed::CompressedPostings;
+use crate::index::posting::compressed::iter::{
+    header_len, posting_len, varint_count, CompressedPostingIter,
+};
+use crate::index::posting::compressed::{CompressedPostings, FORMAT_MARKER, HEADER_SIZE};
 use crate::index::posting::IndexPostingEditor;
 use crate::Result;
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
 use bytestore::components::indexed_file::IndexedFile;
-use bytestore::components::number_seq::compressed::CompressedNumberSequence;
-use bytestore::traits::initiable::Initiable;
 use std::collections::HashMap;
 
 pub struct CompressedPostingEditor<'a, B> {
@@ -67,10 +68,36 @@ where
         Self::ensure_term_in_posting(&mut posting_list, max_tid)?;
         // println!("Term posting ensuring took: {:?}", start.elapsed());
 
+        // New postings get their header prepended. The count of existing ones gets updated after
+        // growing. Postings written without a header stay that way.
+        let mut counts = vec![];
+        for (term_id, data) in terms.iter_mut() {
+            let count = varint_count(data);
+            let existing = posting_list.get(*term_id).unwrap_or(&[]);
+            if existing.is_empty() {
+                let mut with_header = Vec::with_capacity(HEADER_SIZE + data.len());
+                with_header.extend_from_slice(&FORMAT_MARKER);
+                with_header.extend_from_slice(&count.to_le_bytes());
+                with_header.extend_from_slice(data);
+                *data = with_header;
+            } else if header_len(existing) > 0 {
+                let new_count = (posting_len(existing) as u32)
+                    .checked_add(count)
+                    .ok_or(Error::OutOfBounds)?;
+                counts.push((*term_id, new_count));
+            }
+        }
+
         // let start = Instant::now();
         posting_list.grow_multiple_fast(&terms).unwrap();
         // println!("Growing multiple took: {:?}", start.elapsed());
 
+        for (term_id, count) in counts {
+            let mut backend = posting_list.get_backend_mut(term_id)?;
+            backend.data_mut()[FORMAT_MARKER.len()..HEADER_SIZE]
+                .copy_from_slice(&count.to_le_bytes());
+        }
+
         Ok(())
     }
 
@@ -158,7 +185,7 @@ where
         let pending = std::mem::take(&mut self.pending);
         for (post_id, new_mappings) in pending.into_iter().enumerate() {
             if !new_mappings.is_empty() {
-                self.commit_postings(post_id, new_mappings).unwrap();
+                self.commit_postings(post_id, new_mappings)?;
             }
         }
         Ok(())
@@ -172,18 +199,23 @@ fn sort_postings_impl<B: Backend>(
     buff: &mut Vec<u64>,
 ) -> Result<()> {
     let mut backend = posting_list.get_backend_mut(term_id)?;
+    let data = backend.data_mut();
+    if data.is_empty() {
+        return Ok(());
+    }
 
-    let numbers: CompressedNumberSequence<_, u64> = CompressedNumberSequence::init(&mut backend)?;
-    buff.extend(numbers.iter());
+    buff.extend(CompressedPostingIter::new(data));
     buff.sort_unstable();
 
+    let header_len = header_len(data);
+    let data = &mut data[header_len..];
     let mut bytes_written = 0;
     for number in buff.iter() {
-        let rest = &mut backend.data_mut()[bytes_written..];
+        let rest = &mut data[bytes_written..];
         let len = varint_simd::encode_to_slice(*number, rest);
         bytes_written += len as usize;
     }
-    assert_eq!(bytes_written, backend.len());
+    assert_eq!(bytes_written, data.len());
 
     Ok(())
 }
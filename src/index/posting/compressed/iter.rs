@@ -0,0 +1,72 @@
+use super::{FORMAT_MARKER, HEADER_SIZE};
+
+/// Iterator over the varint encoded storage IDs of a compressed posting.
+pub struct CompressedPostingIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    left: usize,
+}
+
+impl<'a> CompressedPostingIter<'a> {
+    #[inline]
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        let left = posting_len(data);
+        Self {
+            data,
+            pos: header_len(data),
+            left,
+        }
+    }
+}
+
+impl<'a> Iterator for CompressedPostingIter<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        let (item, l) =
+            varint_simd::decode::<u64>(&self.data[self.pos..]).expect("Invalid posting data");
+        self.pos += l;
+        self.left -= 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+/// Returns the amount of storage IDs of a compressed posting. Postings with a header have it
+/// stored, older ones get their varints counted. Terms without any postings have no data.
+#[inline]
+pub(crate) fn posting_len(data: &[u8]) -> usize {
+    if !data.starts_with(&FORMAT_MARKER) {
+        return varint_count(data) as usize;
+    }
+    if data.len() < HEADER_SIZE {
+        return 0;
+    }
+    u32::from_le_bytes(data[FORMAT_MARKER.len()..HEADER_SIZE].try_into().unwrap()) as usize
+}
+
+/// Returns the size of the header of a compressed posting which is 0 for postings written
+/// without one.
+#[inline]
+pub(crate) fn header_len(data: &[u8]) -> usize {
+    if data.starts_with(&FORMAT_MARKER) {
+        HEADER_SIZE
+    } else {
+        0
+    }
+}
+
+/// Returns the amount of varints in `data`. Only the last byte of each varint has its most
+/// significant bit unset.
+#[inline]
+pub(crate) fn varint_count(data: &[u8]) -> u32 {
+    data.iter().filter(|i| **i & 0x80 == 0).count() as u32
+}
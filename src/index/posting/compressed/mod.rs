@@ -1,20 +1,30 @@
 pub mod editor;
+pub mod iter;
 
 use crate::error::Error;
-use crate::index::posting::{EditableIndexPosting, IndexPosting};
+use crate::index::posting::{CountablePosting, EditableIndexPosting, IndexPosting};
 use editor::CompressedPostingEditor;
-use bytestore::backend::full::FullBackend;
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
 use bytestore::components::indexed_file::IndexedFile;
 use bytestore::components::multi_file::entry_mut::MFileEntryMut;
 use bytestore::components::multi_file::MultiFile;
-use bytestore::components::number_seq::compressed::iter::OwnedCompressedNumSeqIterator;
-use bytestore::components::number_seq::compressed::CompressedNumberSequence;
 use bytestore::traits::collection::Collection;
 use bytestore::traits::creatable::Creatable;
 use bytestore::traits::initiable::Initiable;
+use iter::CompressedPostingIter;
 
+/// Marks postings that start with a header holding their amount of storage IDs. It is a
+/// non-canonical varint which postings written before the header existed can't start with, so
+/// both formats can be read.
+pub(crate) const FORMAT_MARKER: [u8; 2] = [0x80, 0x00];
+
+/// Size of the header of a posting: The format marker and the amount of storage IDs as u32.
+pub(crate) const HEADER_SIZE: usize = FORMAT_MARKER.len() + 4;
+
+/// Postings that store the storage IDs of each term as varints. Each term's posting starts with
+/// a header holding the amount of storage IDs so its length can be read without decoding.
+/// Postings without the header are still supported and have their varints counted instead.
 pub struct CompressedPostings<B> {
     pub postings: MultiFile<B>,
 }
@@ -89,13 +99,13 @@ where
     }
 
     #[inline]
-    fn posting_backend<'a>(&self, post_id: usize, term_id: u64) -> Option<FullBackend<&'a [u8]>> {
+    fn posting_data<'a>(&self, post_id: usize, term_id: u64) -> Option<&'a [u8]> {
         let ifile: IndexedFile<_> = self.postings.get_backend(post_id)?;
-        let data = ifile.get_backend(term_id.try_into().unwrap()).ok()?;
+        let data = ifile.get(term_id.try_into().ok()?).ok()?;
 
         // Safety:
         // The actual lifetime of the data is bound to <B> which &self is also bound to.
-        Some(unsafe { data.ignore_lifetimes() })
+        Some(unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) })
     }
 }
 
@@ -103,8 +113,7 @@ impl<B> IndexPosting for CompressedPostings<B>
 where
     B: Backend,
 {
-    type PostingRetriever<'a> = OwnedCompressedNumSeqIterator<FullBackend<&'a [u8]>, u64>
-        where Self: 'a;
+    type PostingRetriever<'a> = CompressedPostingIter<'a> where Self: 'a;
 
     #[inline]
     fn posting_retriever(
@@ -112,9 +121,15 @@ where
         post_id: usize,
         term_id: u64,
     ) -> Option<Self::PostingRetriever<'_>> {
-        let backend = self.posting_backend(post_id, term_id)?;
-        let collection = CompressedNumberSequence::init(backend).expect("Failed to init backend");
-        Some(collection.into_iter())
+        let data = self.posting_data(post_id, term_id)?;
+        Some(CompressedPostingIter::new(data))
+    }
+
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        self.posting_data(post_id, term_id)
+            .map(iter::posting_len)
+            .unwrap_or(0)
     }
 
     #[inline]
@@ -123,9 +138,12 @@ where
     }
 }
 
+impl<B> CountablePosting for CompressedPostings<B> where B: Backend {}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::index::entry::overwrite_entries;
     use crate::index::posting::compressed::CompressedPostings;
     use crate::index::posting::IndexPostingEditor;
     use bytestore::traits::creatable::MemCreatable;
@@ -148,6 +166,20 @@ mod test {
         let r = postings.posting_retriever(0, 0).unwrap();
         assert_eq!(r.collect::<Vec<_>>(), vec![0]);
 
+        let mut editor = postings.editor();
+        editor.insert_posts(0, 300, &[1]).unwrap();
+        editor.insert_posts(0, 2, &[1, 2]).unwrap();
+        editor.commit().unwrap();
+        assert_eq!(postings.posting_len(0, 0), 1);
+        assert_eq!(postings.posting_len(0, 1), 3);
+        assert_eq!(postings.posting_len(0, 2), 1);
+        assert_eq!(postings.posting_len(1, 0), 0);
+
+        let mut editor = postings.editor();
+        editor.sort_all_postings().unwrap();
+        let r = postings.posting_retriever(0, 1).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![0, 2, 300]);
+
         let mut postings = CompressedPostings::create_mem_with_capacity(10).unwrap();
 
         let insert_count = 1_000_000u64;
@@ -174,4 +206,52 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn posting_without_header() {
+        let mut postings = CompressedPostings::create_mem_with_capacity(10).unwrap();
+
+        // Postings written before the header was introduced only contain the varints.
+        let mut data = vec![];
+        for id in [5u64, 300, 1] {
+            let (enc, l) = varint_simd::encode(id);
+            data.extend_from_slice(&enc[..l as usize]);
+        }
+        let mut posting_list = postings.posting_list_mut(0).unwrap();
+        overwrite_entries(&mut posting_list, vec![(0, data)]).unwrap();
+
+        assert_eq!(postings.posting_len(0, 0), 3);
+        let r = postings.posting_retriever(0, 0).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![5, 300, 1]);
+
+        let mut editor = postings.editor();
+        editor.insert_posts(0, 2, &[0, 1]).unwrap();
+        editor.commit().unwrap();
+        assert_eq!(postings.posting_len(0, 0), 4);
+        assert_eq!(postings.posting_len(0, 1), 1);
+
+        let mut editor = postings.editor();
+        editor.sort_all_postings().unwrap();
+        let r = postings.posting_retriever(0, 0).unwrap();
+        assert_eq!(r.collect::<Vec<_>>(), vec![1, 2, 5, 300]);
+    }
+
+    #[test]
+    fn count_overflow() {
+        let mut postings = CompressedPostings::create_mem_with_capacity(10).unwrap();
+
+        let mut editor = postings.editor();
+        editor.insert_posts(0, 0, &[0]).unwrap();
+        editor.commit().unwrap();
+
+        // Fake a posting that already holds the maximum amount of storage IDs.
+        let mut posting_list = postings.posting_list_mut(0).unwrap();
+        let mut backend = posting_list.get_backend_mut(0).unwrap();
+        backend.data_mut()[FORMAT_MARKER.len()..HEADER_SIZE]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let mut editor = postings.editor();
+        editor.insert_posts(0, 1, &[0]).unwrap();
+        assert!(editor.commit().is_err());
+    }
 }
@@ -0,0 +1,197 @@
+use crate::index::posting::{CountablePosting, IndexPosting};
+use roaring::RoaringTreemap;
+
+/// Returns the length of the only posting that gets queried if there is exactly one, and its IDs
+/// are known to be unique.
+#[inline]
+pub(crate) fn single_posting_len<P: CountablePosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> Option<usize> {
+    if !P::UNIQUE_IDS || posting_lists.len() != 1 || term_ids.len() != 1 {
+        return None;
+    }
+    Some(postings.posting_len(posting_lists[0] as usize, term_ids[0]))
+}
+
+/// Appends all storage IDs of a term over all given posting lists to `out`.
+#[inline]
+fn term_ids_into<P: IndexPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_id: u64,
+    out: &mut Vec<u64>,
+) {
+    for posting_id in posting_lists {
+        if let Some(retriever) = postings.posting_retriever(*posting_id as usize, term_id) {
+            out.extend(retriever);
+        }
+    }
+}
+
+/// Returns the sum of the posting lengths of a term over all given posting lists.
+#[inline]
+fn term_len<P: IndexPosting + ?Sized>(postings: &P, posting_lists: &[u16], term_id: u64) -> usize {
+    posting_lists
+        .iter()
+        .map(|posting_id| postings.posting_len(*posting_id as usize, term_id))
+        .sum()
+}
+
+/// Counts the unique storage IDs of all terms by collecting them into a bitmap, since the
+/// postings can be unsorted. Postings that are known to be sorted get merged instead, see
+/// `seek::count_union`.
+#[inline]
+pub(crate) fn count_union<P: IndexPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> usize {
+    union_ids(postings, posting_lists, term_ids).len() as usize
+}
+
+/// Returns the unique storage IDs of all terms as bitmap.
+pub(crate) fn union_ids<P: IndexPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> RoaringTreemap {
+    let mut bitmap = RoaringTreemap::new();
+    for term_id in term_ids {
+        for posting_id in posting_lists {
+            if let Some(retriever) = postings.posting_retriever(*posting_id as usize, *term_id) {
+                bitmap.extend(retriever);
+            }
+        }
+    }
+    bitmap
+}
+
+/// Counts the unique storage IDs that are in the postings of all terms.
+#[inline]
+pub(crate) fn count_intersection<P: IndexPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> usize {
+    intersection_ids(postings, posting_lists, term_ids).len()
+}
+
+/// Returns the sorted and unique storage IDs that are in the postings of all terms. Terms get
+/// intersected in order of their posting lengths so the candidate set stays as small as possible.
+pub(crate) fn intersection_ids<P: IndexPosting + ?Sized>(
+    postings: &P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+) -> Vec<u64> {
+    let mut terms: Vec<(usize, u64)> = term_ids
+        .iter()
+        .map(|term_id| (term_len(postings, posting_lists, *term_id), *term_id))
+        .collect();
+    terms.sort_unstable();
+    terms.dedup_by_key(|i| i.1);
+
+    if terms.first().map(|i| i.0).unwrap_or(0) == 0 {
+        return vec![];
+    }
+
+    let mut candidates = Vec::with_capacity(terms[0].0);
+    term_ids_into(postings, posting_lists, terms[0].1, &mut candidates);
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let mut buf = vec![];
+    for (_, term_id) in terms.iter().skip(1) {
+        if candidates.is_empty() {
+            break;
+        }
+
+        buf.clear();
+        term_ids_into(postings, posting_lists, *term_id, &mut buf);
+        buf.sort_unstable();
+
+        let mut other = buf.iter().peekable();
+        candidates.retain(|id| {
+            while other.next_if(|i| *i < id).is_some() {}
+            other.peek().map(|i| *i == id).unwrap_or(false)
+        });
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    use crate::index::dict::IndexDictionary;
+    use crate::index::posting::CountablePosting;
+    use crate::index::preset::{BlockIndex, RoaringIndex};
+    use crate::index::test::{
+        expected, fill_index, index_test_data, query_builder, sorted_test_index, TEST_QUERIES,
+    };
+    use crate::retrieve::build::RetrieverBuilder;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::Creatable;
+    use std::collections::HashMap;
+
+    fn assert_counts<P: CountablePosting, I>(
+        retrieve_builder: &RetrieverBuilder<P, I>,
+        exp_index: &HashMap<String, Vec<String>>,
+        query: &[&str],
+    ) {
+        assert_eq!(
+            retrieve_builder.count_union(),
+            expected(exp_index, query, false).len()
+        );
+        assert_eq!(
+            retrieve_builder.count_intersection(),
+            expected(exp_index, query, true).len()
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let index = sorted_test_index();
+        let exp_index = index_test_data().1;
+
+        for query in TEST_QUERIES {
+            assert_counts(&query_builder(&index, query), &exp_index, query);
+        }
+    }
+
+    #[test]
+    fn test_count_block() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: BlockIndex<_, String, String> = BlockIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        fill_index(index.editor(), &data);
+
+        for query in TEST_QUERIES {
+            assert_counts(&query_builder(&index, query), &exp_index, query);
+        }
+    }
+
+    #[test]
+    fn test_count_bitmap() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: RoaringIndex<_, String, String> = RoaringIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        fill_index(index.editor(), &data);
+
+        for query in TEST_QUERIES {
+            assert_counts(&query_builder(&index, query), &exp_index, query);
+        }
+
+        // Single posting lists are counted by their stored length.
+        let term_id = index.dict().term_id(&"jotoba".to_string()).unwrap();
+        let retrieve_builder = RetrieverBuilder::new(&index)
+            .with_term_ids(&[term_id as u64])
+            .in_posting_lists(&[0]);
+        let exp = data
+            .iter()
+            .step_by(2)
+            .filter(|i| i.0.contains(&"jotoba".to_string()))
+            .count();
+        assert_eq!(retrieve_builder.count_union(), exp);
+    }
+}
@@ -1,7 +1,7 @@
 pub mod editor;
 
 use crate::error::Error;
-use crate::index::posting::{EditableIndexPosting, IndexPosting};
+use crate::index::posting::{CountablePosting, EditableIndexPosting, IndexPosting};
 use editor::DefaultPostingEditor;
 use bytestore::backend::full::FullBackend;
 use bytestore::backend::growable::GrowableBackend;
@@ -117,12 +117,22 @@ where
         Some(collection.into_iter())
     }
 
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        // Storage IDs are stored as plain u64 values.
+        self.posting_backend(post_id, term_id)
+            .map(|i| i.len() / 8)
+            .unwrap_or(0)
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.postings.count()
     }
 }
 
+impl<B> CountablePosting for DefaultPostings<B> where B: Backend {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -146,6 +156,8 @@ mod test {
 
         let r = postings.posting_retriever(0, 0).unwrap();
         assert_eq!(r.collect::<Vec<_>>(), vec![0]);
+        assert_eq!(postings.posting_len(0, 0), 1);
+        assert_eq!(postings.posting_len(1, 0), 0);
 
         let mut postings = DefaultPostings::create_mem_with_capacity(10).unwrap();
 
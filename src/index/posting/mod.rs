@@ -1,7 +1,13 @@
+pub mod adaptive;
+pub mod bitmap;
+pub mod block;
 pub mod compressed;
+pub(crate) mod count;
 pub mod default;
+pub(crate) mod seek;
 
 use crate::Result;
+use roaring::RoaringTreemap;
 
 pub trait IndexPosting {
     type PostingRetriever<'a>: IntoIterator<Item = u64> + 'a
@@ -11,6 +17,16 @@ pub trait IndexPosting {
     fn posting_retriever(&self, post_id: usize, term_id: u64)
         -> Option<Self::PostingRetriever<'_>>;
 
+    /// Returns the amount of storage IDs of a term in the given posting list. Returns 0 if the term
+    /// or posting list doesn't exist. Postings that store their length should override this to
+    /// avoid decoding the posting.
+    #[inline]
+    fn posting_len(&self, post_id: usize, term_id: u64) -> usize {
+        self.posting_retriever(post_id, term_id)
+            .map(|i| i.into_iter().count())
+            .unwrap_or(0)
+    }
+
     fn len(&self) -> usize;
 
     #[inline]
@@ -19,6 +35,43 @@ pub trait IndexPosting {
     }
 }
 
+/// Iterator over sorted storage IDs of a posting that can skip ahead without decoding every ID.
+pub trait SeekablePosting: Iterator<Item = u64> {
+    /// Advances the iterator to the first storage ID that is >= `target`, consumes and returns it.
+    /// Never goes backwards, so IDs that have already been yielded won't be returned again.
+    fn seek(&mut self, target: u64) -> Option<u64>;
+}
+
+/// Postings that can return all storage IDs of a term as bitmap.
+pub trait BitmapPosting {
+    /// Returns the storage IDs of the given term in the posting list as bitmap.
+    fn posting_bitmap(&self, post_id: usize, term_id: u64) -> Option<RoaringTreemap>;
+}
+
+/// Postings that can count the results of a query without materializing the storage IDs.
+pub trait CountablePosting: IndexPosting {
+    /// Whether each posting contains a storage ID at most once. Single postings can then be
+    /// counted by their stored length.
+    const UNIQUE_IDS: bool = false;
+
+    /// Returns the amount of unique storage IDs that are in the postings of at least _one_ of the
+    /// terms in any of the given posting lists.
+    #[inline]
+    fn count_union(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| count::count_union(self, posting_lists, term_ids))
+    }
+
+    /// Returns the amount of unique storage IDs that are in the postings of _all_ terms in any of
+    /// the given posting lists.
+    #[inline]
+    fn count_intersection(&self, posting_lists: &[u16], term_ids: &[u64]) -> usize {
+        count::single_posting_len(self, posting_lists, term_ids)
+            .unwrap_or_else(|| count::count_intersection(self, posting_lists, term_ids))
+    }
+
+}
+
 pub trait EditableIndexPosting {
     type Editor<'a>: IndexPostingEditor
     where
@@ -0,0 +1,121 @@
+use crate::index::posting::{IndexPosting, SeekablePosting};
+
+/// Wraps a seekable posting and keeps the last retrieved storage ID so it doesn't get lost if it
+/// was larger than the requested target.
+pub(crate) struct Cursor<I> {
+    iter: I,
+    curr: Option<u64>,
+    done: bool,
+}
+
+impl<I: SeekablePosting> Cursor<I> {
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            curr: None,
+            done: false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn seek(&mut self, target: u64) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+
+        if let Some(curr) = self.curr {
+            if curr >= target {
+                return Some(curr);
+            }
+        }
+
+        self.curr = self.iter.seek(target);
+        self.done = self.curr.is_none();
+        self.curr
+    }
+}
+
+/// Returns one cursor for each posting list of each term. Terms that are in none of the posting
+/// lists have no cursors.
+pub(crate) fn term_cursors<'a, P, I, F>(
+    postings: &'a P,
+    posting_lists: &[u16],
+    term_ids: &[u64],
+    wrap: F,
+) -> Vec<Vec<Cursor<I>>>
+where
+    P: IndexPosting + ?Sized,
+    I: SeekablePosting,
+    F: Fn(<P::PostingRetriever<'a> as IntoIterator>::IntoIter) -> I,
+{
+    term_ids
+        .iter()
+        .map(|term_id| {
+            posting_lists
+                .iter()
+                .filter_map(|posting_id| postings.posting_retriever(*posting_id as usize, *term_id))
+                .map(|iter| Cursor::new(wrap(iter.into_iter())))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the smallest storage ID >= `target` over all posting lists of a term.
+#[inline]
+pub(crate) fn term_seek<I: SeekablePosting>(cursors: &mut [Cursor<I>], target: u64) -> Option<u64> {
+    cursors.iter_mut().filter_map(|i| i.seek(target)).min()
+}
+
+/// Returns the smallest storage ID >= `target` that is in the postings of all terms.
+pub(crate) fn next_intersection<I: SeekablePosting>(
+    terms: &mut [Vec<Cursor<I>>],
+    mut target: u64,
+) -> Option<u64> {
+    if terms.is_empty() {
+        return None;
+    }
+
+    loop {
+        let mut max = target;
+        for term in terms.iter_mut() {
+            max = max.max(term_seek(term, target)?);
+        }
+
+        if max == target {
+            return Some(target);
+        }
+
+        target = max;
+    }
+}
+
+/// Counts the unique storage IDs in the postings of any term by merging the sorted postings.
+pub(crate) fn count_union<I: SeekablePosting>(mut terms: Vec<Vec<Cursor<I>>>) -> usize {
+    let mut count = 0;
+    let mut target = Some(0);
+    while let Some(t) = target {
+        let next = terms.iter_mut().filter_map(|i| term_seek(i, t)).min();
+        let Some(next) = next else {
+            break;
+        };
+        count += 1;
+        target = next.checked_add(1);
+    }
+    count
+}
+
+/// Counts the unique storage IDs in the postings of all terms by seeking through the sorted
+/// postings.
+pub(crate) fn count_intersection<I: SeekablePosting>(mut terms: Vec<Vec<Cursor<I>>>) -> usize {
+    let mut count = 0;
+    let mut target = Some(0);
+    while let Some(t) = target {
+        let Some(next) = next_intersection(&mut terms, t) else {
+            break;
+        };
+        count += 1;
+        target = next.checked_add(1);
+    }
+    count
+}
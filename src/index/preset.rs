@@ -1,10 +1,21 @@
-use super::{DICT_INDEX, POSTINGS_INDEX, STORAGE_INDEX};
+use super::{DICT_INDEX, META_INDEX, POSTINGS_INDEX, STORAGE_INDEX, SYNONYMS_INDEX};
 use crate::edit::IndexEditor;
+use crate::index::dict::bytegram::bytegram::Bytegram;
+use crate::index::dict::bytegram::BytegramDict;
 use crate::index::dict::default::DefaultDict;
+use crate::index::dict::fst_dict::FstDict;
+use crate::index::dict::ngram::mixed::term::MixedNgram;
+use crate::index::dict::ngram::mixed::MixedNGramDict;
 use crate::index::dict::ngram::ngram::Ngram;
 use crate::index::dict::ngram::NGramDict;
+use crate::index::dict::sorted::SortedDict;
+use crate::index::posting::adaptive::AdaptivePostings;
+use crate::index::posting::bitmap::RoaringPostings;
+use crate::index::posting::block::BlockPostings;
 use crate::index::posting::compressed::CompressedPostings;
 use crate::index::posting::default::DefaultPostings;
+use crate::index::storage::compressed::{Lz4Storage, ZstdStorage};
+use crate::index::storage::dedup::DedupStorage;
 use crate::index::storage::default::DefaultStorage;
 use crate::index::storage::passthrough::PassThroughStorage;
 use crate::index::traits::editable_index::EditableInvertedIndex;
@@ -146,6 +157,414 @@ where
     implement_editable_index!(DefaultDict, PassThroughStorage, CompressedPostings, T, u64);
 }
 
+/// An inverted index that stores postings sorted in blocks with skip data. Retrievers can skip
+/// whole blocks which makes intersecting postings fast.
+pub struct BlockIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> BlockIndex<B, T, S> {
+    index_functions!(DefaultDict, DefaultStorage, BlockPostings, T, S);
+}
+
+impl<B, T, S> BlockIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, DefaultStorage, BlockPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for BlockIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, DefaultStorage, BlockPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for BlockIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for BlockIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, DefaultStorage, BlockPostings, T, S);
+}
+
+/// An inverted index that stores postings as roaring bitmaps. Useful if a lot of terms are
+/// assigned to a large share of all storage items.
+pub struct RoaringIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> RoaringIndex<B, T, S> {
+    index_functions!(DefaultDict, DefaultStorage, RoaringPostings, T, S);
+}
+
+impl<B, T, S> RoaringIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, DefaultStorage, RoaringPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for RoaringIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, DefaultStorage, RoaringPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for RoaringIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for RoaringIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, DefaultStorage, RoaringPostings, T, S);
+}
+
+/// An inverted index that stores postings as roaring bitmaps and uses the provided IDs as storage
+/// items.
+pub struct RoaringIntIndex<B, T> {
+    backend: MultiFile<B>,
+    p: PhantomData<T>,
+}
+
+impl<B, T> RoaringIntIndex<B, T>
+where
+    B: Backend,
+{
+    index_functions!(DefaultDict, PassThroughStorage, RoaringPostings, T, u64);
+}
+
+impl<B, T> RoaringIntIndex<B, T> {
+    index_mut_functions!(DefaultDict, PassThroughStorage, RoaringPostings, T, u64);
+}
+
+impl<B, T> InvertedIndex<T, u64> for RoaringIntIndex<B, T>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+{
+    implement_index_trait!(DefaultDict, PassThroughStorage, RoaringPostings, T, u64);
+}
+
+impl<B, T> InvertedIndexDict<T> for RoaringIntIndex<B, T>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T> EditableInvertedIndex<B, T, u64> for RoaringIntIndex<B, T>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+{
+    implement_editable_index!(DefaultDict, PassThroughStorage, RoaringPostings, T, u64);
+}
+
+/// An inverted index that chooses the smallest posting encoding for each term separately.
+pub struct AdaptiveIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> AdaptiveIndex<B, T, S> {
+    index_functions!(DefaultDict, DefaultStorage, AdaptivePostings, T, S);
+}
+
+impl<B, T, S> AdaptiveIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, DefaultStorage, AdaptivePostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for AdaptiveIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, DefaultStorage, AdaptivePostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for AdaptiveIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for AdaptiveIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, DefaultStorage, AdaptivePostings, T, S);
+}
+
+/// An inverted index that keeps its terms sorted. Allows ordered term operations like range or
+/// prefix lookups.
+pub struct SortedIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> SortedIndex<B, T, S> {
+    index_functions!(SortedDict, DefaultStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> SortedIndex<B, T, S> {
+    index_mut_functions!(SortedDict, DefaultStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for SortedIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + Ord,
+    S: Deser,
+{
+    implement_index_trait!(SortedDict, DefaultStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for SortedIndex<B, T, S>
+where
+    T: Deser + Ord,
+    B: Backend,
+{
+    implement_index_dict_trait!(SortedDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for SortedIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone,
+    S: Deser,
+{
+    implement_editable_index!(SortedDict, DefaultStorage, DefaultPostings, T, S);
+}
+
+/// Similar to [`SortedIndex`] but stores postings compressed.
+pub struct CompressedSortedIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> CompressedSortedIndex<B, T, S> {
+    index_functions!(SortedDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> CompressedSortedIndex<B, T, S> {
+    index_mut_functions!(SortedDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for CompressedSortedIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + Ord,
+    S: Deser,
+{
+    implement_index_trait!(SortedDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for CompressedSortedIndex<B, T, S>
+where
+    T: Deser + Ord,
+    B: Backend,
+{
+    implement_index_dict_trait!(SortedDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for CompressedSortedIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone,
+    S: Deser,
+{
+    implement_editable_index!(SortedDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+/// An inverted index with a compact FST based dictionary and compressed postings. Useful for large
+/// vocabularies. New terms become visible after committing.
+pub struct FstIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> FstIndex<B, T, S> {
+    index_functions!(FstDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> FstIndex<B, T, S> {
+    index_mut_functions!(FstDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for FstIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + AsRef<[u8]>,
+    S: Deser,
+{
+    implement_index_trait!(FstDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for FstIndex<B, T, S>
+where
+    T: Deser + AsRef<[u8]>,
+    B: Backend,
+{
+    implement_index_dict_trait!(FstDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for FstIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + AsRef<[u8]> + Clone,
+    S: Deser,
+{
+    implement_editable_index!(FstDict, DefaultStorage, CompressedPostings, T, S);
+}
+
+/// An inverted index that stores postings compressed and its storage items in LZ4 compressed
+/// blocks.
+pub struct Lz4StorageIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> Lz4StorageIndex<B, T, S> {
+    index_functions!(DefaultDict, Lz4Storage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> Lz4StorageIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, Lz4Storage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for Lz4StorageIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, Lz4Storage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for Lz4StorageIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for Lz4StorageIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, Lz4Storage, CompressedPostings, T, S);
+}
+
+/// An inverted index that stores postings compressed and its storage items in zstd compressed
+/// blocks.
+pub struct ZstdStorageIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> ZstdStorageIndex<B, T, S> {
+    index_functions!(DefaultDict, ZstdStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> ZstdStorageIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, ZstdStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for ZstdStorageIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, ZstdStorage, CompressedPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for ZstdStorageIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for ZstdStorageIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, ZstdStorage, CompressedPostings, T, S);
+}
+
+/// An inverted index that stores byte-identical storage items only once.
+pub struct DedupIndex<B, T, S> {
+    backend: MultiFile<B>,
+    p: PhantomData<(T, S)>,
+}
+
+impl<B: Backend, T, S> DedupIndex<B, T, S> {
+    index_functions!(DefaultDict, DedupStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> DedupIndex<B, T, S> {
+    index_mut_functions!(DefaultDict, DedupStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndex<T, S> for DedupIndex<B, T, S>
+where
+    B: Backend,
+    T: Deser + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_index_trait!(DefaultDict, DedupStorage, DefaultPostings, T, S);
+}
+
+impl<B, T, S> InvertedIndexDict<T> for DedupIndex<B, T, S>
+where
+    T: Deser + hashing::Hash + Eq,
+    B: Backend,
+{
+    implement_index_dict_trait!(DefaultDict, T);
+}
+
+impl<B, T, S> EditableInvertedIndex<B, T, S> for DedupIndex<B, T, S>
+where
+    B: GrowableBackend,
+    T: Deser + Ord + Clone + hashing::Hash + Eq,
+    S: Deser,
+{
+    implement_editable_index!(DefaultDict, DedupStorage, DefaultPostings, T, S);
+}
+
 //                                                        //
 //                           NGram                        //
 //                                                        //
@@ -277,3 +696,164 @@ where
 {
     implement_editable_ngindex_trait!(PassThroughStorage, CompressedPostings, N, u64);
 }
+
+/// An inverted index for ngrams of different lengths up to N characters, for example uni- and
+/// bigrams for CJK text. Use a `MixedNgramGenerator` to create the terms for insertion and
+/// queries. Doesn't apply any compression or other mods.
+pub struct MixedNgramIndex<B, S, const N: usize> {
+    backend: MultiFile<B>,
+    p: PhantomData<S>,
+}
+
+impl<B, S, const N: usize> MixedNgramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    ngram_index_functions!(
+        MixedNGramDict,
+        MixedNgram,
+        DefaultStorage,
+        DefaultPostings,
+        N,
+        u64
+    );
+}
+
+impl<B, S, const N: usize> MixedNgramIndex<B, S, N>
+where
+    S: Deser,
+{
+    ngram_index_mut_functions!(MixedNGramDict, DefaultStorage, DefaultPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndex<MixedNgram<N>, u64> for MixedNgramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    implement_ngindex_trait!(DefaultStorage, DefaultPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndexDict<MixedNgram<N>> for MixedNgramIndex<B, S, N>
+where
+    S: Deser,
+    B: Backend,
+{
+    implement_ngindex_dict_trait!(MixedNGramDict, N);
+}
+
+impl<B, S, const N: usize> EditableInvertedIndex<B, MixedNgram<N>, u64> for MixedNgramIndex<B, S, N>
+where
+    B: GrowableBackend,
+    S: Deser,
+{
+    implement_editable_ngindex_trait!(MixedNGramDict, DefaultStorage, DefaultPostings, N, u64);
+}
+
+/// An inverted index for bytegram terms of N UTF-8 bytes. Needs less space and hashes faster than
+/// DefaultNgramIndex for mostly ASCII text. Doesn't apply any compression or other mods.
+pub struct DefaultBytegramIndex<B, S, const N: usize> {
+    backend: MultiFile<B>,
+    p: PhantomData<S>,
+}
+
+impl<B, S, const N: usize> DefaultBytegramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    ngram_index_functions!(
+        BytegramDict,
+        Bytegram,
+        DefaultStorage,
+        DefaultPostings,
+        N,
+        u64
+    );
+}
+
+impl<B, S, const N: usize> DefaultBytegramIndex<B, S, N>
+where
+    S: Deser,
+{
+    ngram_index_mut_functions!(BytegramDict, DefaultStorage, DefaultPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndex<Bytegram<N>, u64> for DefaultBytegramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    implement_ngindex_trait!(DefaultStorage, DefaultPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndexDict<Bytegram<N>> for DefaultBytegramIndex<B, S, N>
+where
+    S: Deser,
+    B: Backend,
+{
+    implement_ngindex_dict_trait!(BytegramDict, N);
+}
+
+impl<B, S, const N: usize> EditableInvertedIndex<B, Bytegram<N>, u64>
+    for DefaultBytegramIndex<B, S, N>
+where
+    B: GrowableBackend,
+    S: Deser,
+{
+    implement_editable_ngindex_trait!(BytegramDict, DefaultStorage, DefaultPostings, N, u64);
+}
+
+/// A compressed inverted index for bytegram terms of N UTF-8 bytes.
+pub struct CompressedBytegramIndex<B, S, const N: usize> {
+    backend: MultiFile<B>,
+    p: PhantomData<S>,
+}
+
+impl<B, S, const N: usize> CompressedBytegramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    ngram_index_functions!(
+        BytegramDict,
+        Bytegram,
+        DefaultStorage,
+        CompressedPostings,
+        N,
+        u64
+    );
+}
+
+impl<B, S, const N: usize> CompressedBytegramIndex<B, S, N>
+where
+    S: Deser,
+{
+    ngram_index_mut_functions!(BytegramDict, DefaultStorage, CompressedPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndex<Bytegram<N>, u64> for CompressedBytegramIndex<B, S, N>
+where
+    B: Backend,
+    S: Deser,
+{
+    implement_ngindex_trait!(DefaultStorage, CompressedPostings, N, u64);
+}
+
+impl<B, S, const N: usize> InvertedIndexDict<Bytegram<N>> for CompressedBytegramIndex<B, S, N>
+where
+    S: Deser,
+    B: Backend,
+{
+    implement_ngindex_dict_trait!(BytegramDict, N);
+}
+
+impl<B, S, const N: usize> EditableInvertedIndex<B, Bytegram<N>, u64>
+    for CompressedBytegramIndex<B, S, N>
+where
+    B: GrowableBackend,
+    S: Deser,
+{
+    implement_editable_ngindex_trait!(BytegramDict, DefaultStorage, CompressedPostings, N, u64);
+}
@@ -0,0 +1,43 @@
+use crate::Result;
+
+/// Compression level used for zstd blocks.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression algorithm for the blocks of a [`CompressedStorage`].
+///
+/// [`CompressedStorage`]: super::CompressedStorage
+pub trait BlockCodec {
+    fn compress(data: &[u8]) -> Result<Vec<u8>>;
+
+    fn decompress(data: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// LZ4 compression. Fast to decompress but compresses less than zstd.
+pub struct Lz4;
+
+impl BlockCodec for Lz4 {
+    #[inline]
+    fn compress(data: &[u8]) -> Result<Vec<u8>> {
+        Ok(lz4_flex::compress_prepend_size(data))
+    }
+
+    #[inline]
+    fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+        Ok(lz4_flex::decompress_size_prepended(data)?)
+    }
+}
+
+/// Zstd compression. Compresses better than LZ4 but is slower to decompress.
+pub struct Zstd;
+
+impl BlockCodec for Zstd {
+    #[inline]
+    fn compress(data: &[u8]) -> Result<Vec<u8>> {
+        Ok(zstd::bulk::compress(data, ZSTD_LEVEL)?)
+    }
+
+    #[inline]
+    fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+        Ok(zstd::stream::decode_all(data)?)
+    }
+}
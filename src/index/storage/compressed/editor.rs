@@ -0,0 +1,78 @@
+use super::codec::BlockCodec;
+use super::{encode_block, encode_info, push_item, CompressedStorage, BLOCK_SIZE};
+use crate::error::Error;
+use crate::index::entry::overwrite_entries;
+use crate::index::storage::{IndexStorage, IndexStorageEditor, StorageInsertionResult};
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::traits::deser::Deser;
+
+pub struct CompressedStorageEditor<'a, B, S, C> {
+    storage: &'a mut CompressedStorage<B, S, C>,
+}
+
+impl<'a, B, S, C> CompressedStorageEditor<'a, B, S, C> {
+    #[inline]
+    pub(super) fn new(storage: &'a mut CompressedStorage<B, S, C>) -> Self {
+        Self { storage }
+    }
+}
+
+impl<'a, B, S, C> IndexStorageEditor<S> for CompressedStorageEditor<'a, B, S, C>
+where
+    B: GrowableBackend,
+    S: Deser,
+    C: BlockCodec,
+{
+    fn insert_items(&mut self, items: &[S]) -> Result<StorageInsertionResult> {
+        if items.is_empty() {
+            return Err(Error::UnsupportedOperation);
+        }
+
+        let first_id = self.storage.len() as u64;
+
+        let mut block_id = self.storage.block_count();
+        let mut block_first = first_id;
+        let mut block_count = 0u32;
+        let mut block = Vec::with_capacity(BLOCK_SIZE * 2);
+
+        // Continue the last block if it isn't full yet.
+        if let Some(last) = block_id.checked_sub(1) {
+            let (first, count) = self.storage.block_info(last).ok_or(Error::Internal)?;
+            let last_block = self.storage.load_block(last)?;
+            if last_block.data().len() < BLOCK_SIZE {
+                block.extend_from_slice(last_block.data());
+                block_id = last;
+                block_first = first;
+                block_count = count;
+            }
+        }
+
+        let mut blocks = vec![];
+        let mut infos = vec![];
+        for item in items {
+            push_item(&mut block, &bincode::serialize(item)?);
+            block_count += 1;
+
+            if block.len() >= BLOCK_SIZE {
+                blocks.push((block_id, encode_block::<C>(&block)?));
+                infos.push((block_id, encode_info(block_first, block_count)));
+                block_id += 1;
+                block_first += block_count as u64;
+                block_count = 0;
+                block.clear();
+            }
+        }
+        if block_count > 0 {
+            blocks.push((block_id, encode_block::<C>(&block)?));
+            infos.push((block_id, encode_info(block_first, block_count)));
+        }
+
+        // The last block might have changed.
+        self.storage.clear_cache();
+        overwrite_entries(&mut self.storage.blocks_mut(), blocks)?;
+        overwrite_entries(&mut self.storage.block_infos_mut(), infos)?;
+
+        Ok(StorageInsertionResult::First(first_id))
+    }
+}
@@ -0,0 +1,410 @@
+pub mod codec;
+mod editor;
+
+use crate::error::Error;
+use crate::index::storage::{BorrowItem, BorrowedIndexStorage, EditableIndexStorage, IndexStorage};
+use crate::Result;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
+use bytestore::traits::initiable::Initiable;
+use codec::{BlockCodec, Lz4, Zstd};
+use editor::CompressedStorageEditor;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+/// Entry of the compressed blocks.
+const BLOCKS_INDEX: usize = 0;
+/// Entry of the ID of the first item and the item count of each block.
+const BLOCK_INFO_INDEX: usize = 1;
+
+/// Uncompressed size at which a block is full and a new one gets started.
+pub(crate) const BLOCK_SIZE: usize = 16 * 1024;
+/// Amount of decompressed blocks kept in the cache.
+const CACHE_SIZE: usize = 4;
+/// Size of the length prefixes of blocks and items.
+const LEN_SIZE: usize = 4;
+/// Size of a block info, the u64 ID of the first item and the u32 item count.
+const INFO_SIZE: usize = 12;
+
+/// A storage that packs items into blocks of roughly 16 KB and compresses each block with `C`.
+/// Needs much less space than [`DefaultStorage`] for larger items, but `get_item` has to
+/// decompress the whole block. The most recently used blocks are cached, so keep the storage
+/// around when retrieving many items instead of getting it from the index for each item.
+///
+/// [`DefaultStorage`]: crate::index::storage::default::DefaultStorage
+pub struct CompressedStorage<B, S, C> {
+    pub backend: MultiFile<B>,
+    cache: Mutex<Vec<(usize, Arc<Block>)>>,
+    _p1: PhantomData<(S, C)>,
+}
+
+/// A [`CompressedStorage`] with LZ4 compressed blocks.
+pub type Lz4Storage<B, S> = CompressedStorage<B, S, Lz4>;
+
+/// A [`CompressedStorage`] with zstd compressed blocks.
+pub type ZstdStorage<B, S> = CompressedStorage<B, S, Zstd>;
+
+impl<B, S, C> Initiable<B> for CompressedStorage<B, S, C>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self {
+            backend,
+            cache: Mutex::default(),
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, S, C> Creatable<B> for CompressedStorage<B, S, C>
+where
+    B: GrowableBackend,
+{
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        Ok(Self {
+            backend,
+            cache: Mutex::default(),
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, S, C> CompressedStorage<B, S, C>
+where
+    B: Backend,
+{
+    /// Returns the compressed blocks.
+    #[inline]
+    fn blocks(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(BLOCKS_INDEX).unwrap()
+    }
+
+    /// Returns the infos of all blocks.
+    #[inline]
+    fn block_infos(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(BLOCK_INFO_INDEX).unwrap()
+    }
+
+    /// Returns the amount of blocks.
+    #[inline]
+    pub(crate) fn block_count(&self) -> usize {
+        self.block_infos().count()
+    }
+
+    /// Returns the ID of the first item and the item count of `block`.
+    #[inline]
+    pub(crate) fn block_info(&self, block: usize) -> Option<(u64, u32)> {
+        decode_info(self.block_infos().get(block).ok()?)
+    }
+
+    /// Returns the block containing the item with the ID `id`.
+    fn find_block(&self, id: u64) -> Option<usize> {
+        let infos = self.block_infos();
+        let (mut low, mut high) = (0, infos.count());
+        while low < high {
+            let mid = (low + high) / 2;
+            let (first, count) = decode_info(infos.get(mid).ok()?)?;
+            if id < first {
+                high = mid;
+            } else if id >= first + count as u64 {
+                low = mid + 1;
+            } else {
+                return Some(mid);
+            }
+        }
+        None
+    }
+
+    /// Clears the cache of decompressed blocks.
+    #[inline]
+    pub(crate) fn clear_cache(&mut self) {
+        self.cache.get_mut().unwrap().clear();
+    }
+}
+
+impl<B, S, C> CompressedStorage<B, S, C>
+where
+    B: Backend,
+    C: BlockCodec,
+{
+    /// Returns the decompressed `block`, either from the cache or by decompressing it.
+    pub(crate) fn load_block(&self, block: usize) -> Result<Arc<Block>> {
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(pos) = cache.iter().position(|i| i.0 == block) {
+            // Move to the end so the least recently used block is at the start.
+            let cached = cache.remove(pos);
+            let data = cached.1.clone();
+            cache.push(cached);
+            return Ok(data);
+        }
+
+        let blocks = self.blocks();
+        let data = blocks.get(block)?;
+        if data.len() < LEN_SIZE {
+            return Err(Error::Internal);
+        }
+        let len = u32::from_le_bytes(data[..LEN_SIZE].try_into().unwrap()) as usize;
+        let compressed = data.get(LEN_SIZE..LEN_SIZE + len).ok_or(Error::Internal)?;
+        let decompressed = Arc::new(Block::new(C::decompress(compressed)?)?);
+
+        if cache.len() >= CACHE_SIZE {
+            cache.remove(0);
+        }
+        cache.push((block, decompressed.clone()));
+        Ok(decompressed)
+    }
+
+    /// Decompresses the block of the item with the ID `id` and deserializes the item from it.
+    fn deserialize_item<T: DeserializeOwned>(&self, id: usize) -> Result<T> {
+        let block_id = self.find_block(id as u64).ok_or(Error::OutOfBounds)?;
+        let (first, _) = self.block_info(block_id).ok_or(Error::OutOfBounds)?;
+        let block = self.load_block(block_id)?;
+        let data = block
+            .item((id as u64 - first) as usize)
+            .ok_or(Error::OutOfBounds)?;
+        Ok(bincode::deserialize(data)?)
+    }
+}
+
+impl<B, S, C> CompressedStorage<B, S, C>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn blocks_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(BLOCKS_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn block_infos_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(BLOCK_INFO_INDEX).unwrap()
+    }
+}
+
+impl<B, S, C> IndexStorage<S> for CompressedStorage<B, S, C>
+where
+    B: Backend,
+    S: DeserializeOwned,
+    C: BlockCodec,
+{
+    #[inline]
+    fn get_item(&self, id: usize) -> Result<S> {
+        self.deserialize_item(id)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        let Some(last) = self.block_count().checked_sub(1) else {
+            return 0;
+        };
+        self.block_info(last)
+            .map(|(first, count)| first as usize + count as usize)
+            .unwrap_or(0)
+    }
+}
+
+/// Decompressed blocks can be evicted from the cache at any time, so only items whose borrowed
+/// form doesn't borrow from the data, like integers, can be returned without an owned copy.
+impl<B, S, C> BorrowedIndexStorage<S> for CompressedStorage<B, S, C>
+where
+    B: Backend,
+    S: BorrowItem + DeserializeOwned,
+    for<'a> S::Borrowed<'a>: DeserializeOwned,
+    C: BlockCodec,
+{
+    #[inline]
+    fn get_item_ref(&self, id: usize) -> Result<S::Borrowed<'_>> {
+        self.deserialize_item(id)
+    }
+}
+
+impl<B, S, C> EditableIndexStorage<S> for CompressedStorage<B, S, C>
+where
+    B: GrowableBackend,
+    S: Deser,
+    C: BlockCodec,
+{
+    type Editor<'a> = CompressedStorageEditor<'a, B, S, C> where Self: 'a, S: 'a, B: 'a;
+
+    /// Returns an editor to modify the current storage.
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        CompressedStorageEditor::new(self)
+    }
+}
+
+/// A decompressed block. Each item is prefixed with its u32 length.
+pub(crate) struct Block {
+    data: Vec<u8>,
+    items: Vec<Range<usize>>,
+}
+
+impl Block {
+    fn new(data: Vec<u8>) -> Result<Self> {
+        let mut items = vec![];
+        let mut pos = 0;
+        while pos < data.len() {
+            let len_bytes = data.get(pos..pos + LEN_SIZE).ok_or(Error::Internal)?;
+            let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+            let start = pos + LEN_SIZE;
+            if start + len > data.len() {
+                return Err(Error::Internal);
+            }
+            items.push(start..start + len);
+            pos = start + len;
+        }
+        Ok(Self { data, items })
+    }
+
+    /// Returns the raw data of the block.
+    #[inline]
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the serialized item at `pos` within the block.
+    #[inline]
+    fn item(&self, pos: usize) -> Option<&[u8]> {
+        Some(&self.data[self.items.get(pos)?.clone()])
+    }
+}
+
+/// Appends a serialized item to the raw data of a block.
+#[inline]
+pub(crate) fn push_item(block: &mut Vec<u8>, item: &[u8]) {
+    block.extend_from_slice(&(item.len() as u32).to_le_bytes());
+    block.extend_from_slice(item);
+}
+
+/// Compresses a block and prefixes it with its compressed length. Blocks get overwritten when
+/// items are added, so the length of the data has to be stored.
+#[inline]
+pub(crate) fn encode_block<C: BlockCodec>(block: &[u8]) -> Result<Vec<u8>> {
+    let compressed = C::compress(block)?;
+    let mut out = Vec::with_capacity(LEN_SIZE + compressed.len());
+    out.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    out.extend_from_slice(&compressed);
+    Ok(out)
+}
+
+#[inline]
+pub(crate) fn encode_info(first: u64, count: u32) -> Vec<u8> {
+    let mut out = Vec::with_capacity(INFO_SIZE);
+    out.extend_from_slice(&first.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out
+}
+
+#[inline]
+fn decode_info(data: &[u8]) -> Option<(u64, u32)> {
+    if data.len() < INFO_SIZE {
+        return None;
+    }
+    let first = u64::from_le_bytes(data[..8].try_into().unwrap());
+    let count = u32::from_le_bytes(data[8..INFO_SIZE].try_into().unwrap());
+    Some((first, count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::entry::overwrite_entries;
+    use crate::index::preset::Lz4StorageIndex;
+    use crate::index::storage::IndexStorageEditor;
+    use crate::index::test::{
+        expected, fill_index, index_test_data, query_builder, retrieved_items, TEST_QUERIES,
+    };
+    use crate::retrieve::retriever::default::DefaultRetriever;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::MemCreatable;
+
+    fn test_storage<C: BlockCodec>() {
+        let mut storage = CompressedStorage::<_, String, C>::create_mem_with_capacity(0).unwrap();
+        let items: Vec<_> = (0..5000).map(|i| format!("stored item {i}")).collect();
+
+        // Insert in multiple batches to continue the last block.
+        let mut editor = storage.editor();
+        for chunk in items.chunks(1500) {
+            editor.insert_items(chunk).unwrap();
+        }
+
+        assert_eq!(storage.len(), items.len());
+        assert!(storage.block_count() > 1);
+        let raw_len: usize = items.iter().map(|i| i.len()).sum();
+        let compressed_len: usize = (0..storage.block_count())
+            .map(|i| storage.blocks().get(i).unwrap().len())
+            .sum();
+        assert!(compressed_len < raw_len);
+
+        for (id, item) in items.iter().enumerate() {
+            assert_eq!(storage.get_item(id).ok().as_ref(), Some(item));
+        }
+        assert!(storage.get_item(items.len()).is_err());
+    }
+
+    #[test]
+    fn truncated_block() {
+        let mut storage = CompressedStorage::<_, String, Lz4>::create_mem_with_capacity(0).unwrap();
+        let items: Vec<_> = (0..10).map(|i| format!("stored item {i}")).collect();
+        storage.editor().insert_items(&items).unwrap();
+
+        // Length prefix pointing past the end of the block.
+        let len = u32::MAX.to_le_bytes().to_vec();
+        overwrite_entries(&mut storage.blocks_mut(), vec![(0, len)]).unwrap();
+        assert!(storage.load_block(0).is_err());
+        assert!(storage.get_item(0).is_err());
+    }
+
+    #[test]
+    fn borrowed_items() {
+        let mut storage = CompressedStorage::<_, u32, Lz4>::create_mem_with_capacity(0).unwrap();
+        let items: Vec<u32> = (0..100).collect();
+        storage.editor().insert_items(&items).unwrap();
+
+        for (id, item) in items.iter().enumerate() {
+            assert_eq!(storage.get_item_ref(id).unwrap(), *item);
+        }
+        assert!(storage.get_item_ref(items.len()).is_err());
+    }
+
+    #[test]
+    fn lz4() {
+        test_storage::<Lz4>();
+    }
+
+    #[test]
+    fn zstd() {
+        test_storage::<Zstd>();
+    }
+
+    #[test]
+    fn index() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: Lz4StorageIndex<_, String, String> =
+            Lz4StorageIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        fill_index(index.editor(), &data);
+
+        for query in TEST_QUERIES {
+            let retrieve_builder = query_builder(&index, query).unique();
+            let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+            let res = retrieved_items(&index, retriever);
+            assert_eq!(res, expected(&exp_index, query, false));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use super::{DedupStorage, ItemHash};
+use crate::error::Error;
+use crate::index::entry::overwrite_entries;
+use crate::index::storage::{IndexStorage, IndexStorageEditor, StorageInsertionResult};
+use crate::Result;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::traits::deser::Deser;
+use std::collections::HashMap;
+
+pub struct DedupStorageEditor<'a, B, S> {
+    storage: &'a mut DedupStorage<B, S>,
+}
+
+impl<'a, B, S> DedupStorageEditor<'a, B, S> {
+    #[inline]
+    pub(super) fn new(storage: &'a mut DedupStorage<B, S>) -> Self {
+        Self { storage }
+    }
+}
+
+impl<'a, B, S> IndexStorageEditor<S> for DedupStorageEditor<'a, B, S>
+where
+    B: GrowableBackend,
+    S: Deser,
+{
+    fn insert_items(&mut self, items: &[S]) -> Result<StorageInsertionResult> {
+        if items.is_empty() {
+            return Err(Error::UnsupportedOperation);
+        }
+
+        let mut next_id = self.storage.len() as u64;
+        let mut ids = Vec::with_capacity(items.len());
+        let mut new_items: Vec<(usize, Vec<u8>)> = vec![];
+        // Hashes of the new items mapped to their position in `new_items`.
+        let mut new_hashes: HashMap<u64, usize> = HashMap::new();
+
+        for item in items {
+            let data = bincode::serialize(item)?;
+            let hash = ItemHash::new(&data);
+
+            let pending = new_hashes
+                .get(&hash.0)
+                .filter(|i| new_items[**i].1 == data)
+                .map(|i| new_items[*i].0 as u64);
+            if let Some(id) = pending.or_else(|| self.storage.find(hash, &data)) {
+                ids.push(id);
+                continue;
+            }
+
+            ids.push(next_id);
+            // Items colliding with the hash of a different item are stored but not deduplicated.
+            if !new_hashes.contains_key(&hash.0) && self.storage.hashes().get(&hash).is_none() {
+                new_hashes.insert(hash.0, new_items.len());
+            }
+            new_items.push((next_id as usize, data));
+            next_id += 1;
+        }
+
+        {
+            let mut hashes = self.storage.hashes_mut();
+            hashes.grow_to(hashes.len() + new_hashes.len())?;
+            for (hash, pos) in new_hashes {
+                hashes.insert(&ItemHash(hash), &(new_items[pos].0 as u64))?;
+            }
+        }
+        overwrite_entries(&mut self.storage.items_mut(), new_items)?;
+
+        Ok(StorageInsertionResult::Ids(ids))
+    }
+}
@@ -0,0 +1,220 @@
+mod editor;
+
+use crate::index::storage::{BorrowItem, BorrowedIndexStorage, EditableIndexStorage, IndexStorage};
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::map::{hashing, FMap};
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
+use bytestore::traits::initiable::Initiable;
+use editor::DedupStorageEditor;
+use highway::{HighwayHash, HighwayHasher};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Entry of the serialized items ordered by their IDs.
+const ITEMS_INDEX: usize = 0;
+/// Entry of the map from item hashes to the ID of the first item with that hash.
+const HASHES_INDEX: usize = 1;
+
+/// A storage that stores byte-identical items only once. Inserting an item that is already in
+/// the storage returns the ID of the existing item, so postings of both point to the same copy.
+pub struct DedupStorage<B, S> {
+    pub backend: MultiFile<B>,
+    _p1: PhantomData<S>,
+}
+
+/// Highway hash of a serialized item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ItemHash(u64);
+
+impl ItemHash {
+    #[inline]
+    pub(crate) fn new(data: &[u8]) -> Self {
+        Self(HighwayHasher::default().hash64(data))
+    }
+}
+
+impl hashing::Hash for ItemHash {
+    #[inline]
+    fn hash(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<B, S> Initiable<B> for DedupStorage<B, S>
+where
+    B: Backend,
+{
+    #[inline]
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, S> Creatable<B> for DedupStorage<B, S>
+where
+    B: GrowableBackend,
+{
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        backend.insert_new_backend::<FMap<_, ItemHash, u64>>()?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, S> DedupStorage<B, S>
+where
+    B: Backend,
+{
+    /// Returns the serialized items ordered by their IDs.
+    #[inline]
+    pub(crate) fn items(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(ITEMS_INDEX).unwrap()
+    }
+
+    /// Returns the map from item hashes to item IDs.
+    #[inline]
+    pub(crate) fn hashes(&self) -> FMap<GeneralSubBackend, ItemHash, u64> {
+        self.backend.get_backend(HASHES_INDEX).unwrap()
+    }
+
+    /// Returns the ID of the item serialized as `data` if it is already stored.
+    pub(crate) fn find(&self, hash: ItemHash, data: &[u8]) -> Option<u64> {
+        let id = self.hashes().get(&hash)?;
+        // Different items can have the same hash.
+        (self.items().get(id as usize).ok()? == data).then_some(id)
+    }
+}
+
+impl<B, S> DedupStorage<B, S>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    pub(crate) fn items_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(ITEMS_INDEX).unwrap()
+    }
+
+    #[inline]
+    pub(crate) fn hashes_mut(&mut self) -> FMap<MFileEntryMut<B>, ItemHash, u64> {
+        self.backend.get_backend_mut(HASHES_INDEX).unwrap()
+    }
+}
+
+impl<B, S> IndexStorage<S> for DedupStorage<B, S>
+where
+    B: Backend,
+    S: DeserializeOwned,
+{
+    #[inline]
+    fn get_item(&self, id: usize) -> crate::Result<S> {
+        Ok(bincode::deserialize(self.items().get(id)?)?)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.items().count()
+    }
+}
+
+impl<B, S> BorrowedIndexStorage<S> for DedupStorage<B, S>
+where
+    B: Backend,
+    S: BorrowItem + DeserializeOwned,
+{
+    #[inline]
+    fn get_item_ref(&self, id: usize) -> crate::Result<S::Borrowed<'_>> {
+        let items = self.items();
+        let data = items.get(id)?;
+
+        // Safety:
+        // The actual lifetime of the data is bound to <B> which &self is also bound to. `items`
+        // is only a temporary handle pointing into <B> and doesn't own the data, so dropping it at
+        // the end of this function doesn't invalidate the slice.
+        let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+        Ok(bincode::deserialize(data)?)
+    }
+}
+
+impl<B, S> EditableIndexStorage<S> for DedupStorage<B, S>
+where
+    B: GrowableBackend,
+    S: Deser,
+{
+    type Editor<'a> = DedupStorageEditor<'a, B, S> where Self: 'a, S: 'a, B: 'a;
+
+    /// Returns an editor to modify the current storage.
+    #[inline]
+    fn editor(&mut self) -> Self::Editor<'_> {
+        DedupStorageEditor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::preset::DedupIndex;
+    use crate::index::storage::{IndexStorageEditor, StorageInsertionResult};
+    use crate::index::test::{
+        expected, fill_index, index_test_data, query_builder, retrieved_items, TEST_QUERIES,
+    };
+    use crate::retrieve::retriever::default::DefaultRetriever;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::MemCreatable;
+
+    #[test]
+    fn dedup() {
+        let mut storage = DedupStorage::<_, String>::create_mem_with_capacity(0).unwrap();
+        let items: Vec<_> = ["a", "b", "a", "c", "b"].map(|i| i.to_string()).into();
+
+        let mut editor = storage.editor();
+        let StorageInsertionResult::Ids(ids) = editor.insert_items(&items).unwrap() else {
+            panic!("expected IDs");
+        };
+        assert_eq!(ids, [0, 1, 0, 2, 1]);
+        let StorageInsertionResult::Ids(ids) = editor.insert_items(&items[2..]).unwrap() else {
+            panic!("expected IDs");
+        };
+        assert_eq!(ids, [0, 2, 1]);
+
+        assert_eq!(storage.len(), 3);
+        for (id, item) in ["a", "b", "c"].iter().enumerate() {
+            assert_eq!(storage.get_item(id).unwrap(), *item);
+            assert_eq!(storage.get_item_ref(id).unwrap(), *item);
+        }
+    }
+
+    #[test]
+    fn index() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DedupIndex<_, String, String> = DedupIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+
+        // Inserting the same sentences again doesn't store another copy of them.
+        fill_index(index.editor(), &data);
+        fill_index(index.editor(), &data);
+        assert_eq!(index.storage().len(), data.len());
+
+        for query in TEST_QUERIES {
+            let retrieve_builder = query_builder(&index, query).unique();
+            let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+            let res = retrieved_items(&index, retriever);
+            assert_eq!(res, expected(&exp_index, query, false));
+        }
+    }
+}
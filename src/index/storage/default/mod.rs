@@ -1,6 +1,6 @@
 mod editor;
 
-use crate::index::storage::{EditableIndexStorage, IndexStorage};
+use crate::index::storage::{BorrowItem, BorrowedIndexStorage, EditableIndexStorage, IndexStorage};
 use editor::StorageEditor;
 use bytestore::backend::growable::GrowableBackend;
 use bytestore::backend::Backend;
@@ -61,6 +61,20 @@ where
     }
 }
 
+impl<B, S> BorrowedIndexStorage<S> for DefaultStorage<B, S>
+where
+    B: Backend,
+    S: BorrowItem + DeserializeOwned,
+{
+    /// Deserializes the item directly from the backend. For memory mapped backends this doesn't
+    /// copy or allocate for strings and byte vectors.
+    #[inline]
+    fn get_item_ref(&self, id: usize) -> crate::Result<S::Borrowed<'_>> {
+        let data = self.backend.get(id)?;
+        Ok(bincode::deserialize(data)?)
+    }
+}
+
 impl<B, S> EditableIndexStorage<S> for DefaultStorage<B, S>
 where
     B: GrowableBackend,
@@ -78,7 +92,9 @@ where
 #[cfg(test)]
 mod test {
     use crate::index::storage::default::DefaultStorage;
-    use crate::index::storage::{EditableIndexStorage, IndexStorage, IndexStorageEditor};
+    use crate::index::storage::{
+        BorrowedIndexStorage, EditableIndexStorage, IndexStorage, IndexStorageEditor,
+    };
     use bytestore::traits::creatable::MemCreatable;
 
     #[test]
@@ -96,4 +112,16 @@ mod test {
 
         assert_eq!(storage.get_item(1000).ok(), None);
     }
+
+    #[test]
+    fn borrowed() {
+        let mut storage = DefaultStorage::<_, String>::create_mem_with_capacity(0).unwrap();
+        let items: Vec<_> = (0..100).map(|i| format!("item {i}")).collect();
+        storage.editor().insert_items(&items).unwrap();
+
+        for (id, item) in items.iter().enumerate() {
+            assert_eq!(storage.get_item_ref(id).ok(), Some(item.as_str()));
+        }
+        assert!(storage.get_item_ref(items.len()).is_err());
+    }
 }
@@ -1,7 +1,10 @@
+pub mod compressed;
+pub mod dedup;
 pub mod default;
 pub mod passthrough;
 
 use crate::Result;
+use serde::Deserialize;
 
 /// Trait defining behavior of storages in an index.
 pub trait IndexStorage<S> {
@@ -24,6 +27,39 @@ pub trait IndexStorage<S> {
     }
 }
 
+/// Storages that can return items borrowing from their backend instead of allocating. Useful for
+/// hot retrieval loops over memory mapped indexes.
+pub trait BorrowedIndexStorage<S: BorrowItem>: IndexStorage<S> {
+    /// Returns the borrowed form of an item from the storage by its id.
+    fn get_item_ref(&self, id: usize) -> Result<S::Borrowed<'_>>;
+}
+
+/// Storage items that have a borrowed form which can be deserialized from the serialized owned
+/// item without allocating, eg. `&str` for `String`.
+pub trait BorrowItem {
+    type Borrowed<'a>: Deserialize<'a>;
+}
+
+impl BorrowItem for String {
+    type Borrowed<'a> = &'a str;
+}
+
+impl BorrowItem for Vec<u8> {
+    type Borrowed<'a> = &'a [u8];
+}
+
+macro_rules! borrow_item_copy {
+    ($($t:ty),*) => {
+        $(
+            impl BorrowItem for $t {
+                type Borrowed<'a> = $t;
+            }
+        )*
+    };
+}
+
+borrow_item_copy!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, bool, char);
+
 pub trait EditableIndexStorage<S>: IndexStorage<S> {
     type Editor<'a>: IndexStorageEditor<S>
     where
@@ -0,0 +1,196 @@
+use crate::error::Error;
+use crate::Result;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::backend::growable::GrowableBackend;
+use bytestore::backend::Backend;
+use bytestore::components::indexed_file::IndexedFile;
+use bytestore::components::map::{hashing, FMap};
+use bytestore::components::multi_file::entry_mut::MFileEntryMut;
+use bytestore::components::multi_file::MultiFile;
+use bytestore::traits::collection::Collection;
+use bytestore::traits::creatable::Creatable;
+use bytestore::traits::deser::Deser;
+use bytestore::traits::initiable::Initiable;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/// Entry of the map from terms to the ID of their synonym group.
+const MAP_INDEX: usize = 0;
+/// Entry of the synonym groups ordered by their IDs.
+const GROUPS_INDEX: usize = 1;
+
+/// Groups of phrases that mean the same, eg. "kanji" and "chinese character". Each phrase is a
+/// list of terms. Phrases consisting of a single term can be looked up to get all other phrases of
+/// their group.
+pub struct SynonymMap<B, T> {
+    pub backend: MultiFile<B>,
+    _p1: PhantomData<T>,
+}
+
+impl<B, T> Initiable<B> for SynonymMap<B, T>
+where
+    B: Backend,
+{
+    fn init(backend: B) -> bytestore::Result<Self> {
+        let backend = MultiFile::init(backend)?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, T> Creatable<B> for SynonymMap<B, T>
+where
+    B: GrowableBackend,
+{
+    fn with_capacity(backend: B, capacity: usize) -> bytestore::Result<Self> {
+        let mut backend = MultiFile::with_capacity(backend, capacity)?;
+        // IMPORTANT: These must be in the same order as the entry constants!
+        backend.insert_new_backend::<FMap<_, T, u32>>()?;
+        backend.insert_new_backend::<IndexedFile<_>>()?;
+        Ok(Self {
+            backend,
+            _p1: PhantomData,
+        })
+    }
+}
+
+impl<B, T> SynonymMap<B, T>
+where
+    B: Backend,
+{
+    /// Returns the map from terms to the ID of their group.
+    #[inline]
+    fn map(&self) -> FMap<GeneralSubBackend, T, u32> {
+        self.backend.get_backend(MAP_INDEX).unwrap()
+    }
+
+    /// Returns all groups ordered by their IDs.
+    #[inline]
+    fn groups(&self) -> IndexedFile<GeneralSubBackend> {
+        self.backend.get_backend(GROUPS_INDEX).unwrap()
+    }
+
+    /// Returns the amount of synonym groups.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.groups().count()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<B, T> SynonymMap<B, T>
+where
+    B: GrowableBackend,
+{
+    #[inline]
+    fn map_mut(&mut self) -> FMap<MFileEntryMut<B>, T, u32> {
+        self.backend.get_backend_mut(MAP_INDEX).unwrap()
+    }
+
+    #[inline]
+    fn groups_mut(&mut self) -> IndexedFile<MFileEntryMut<B>> {
+        self.backend.get_backend_mut(GROUPS_INDEX).unwrap()
+    }
+}
+
+impl<B, T> SynonymMap<B, T>
+where
+    B: Backend,
+    T: hashing::Hash + Eq + Deser + Serialize + DeserializeOwned,
+{
+    /// Returns all phrases that are synonyms of `term`, excluding `term` itself.
+    pub fn synonyms(&self, term: &T) -> Vec<Vec<T>> {
+        let Some(group) = self.map().get(term) else {
+            return vec![];
+        };
+        let phrases: Vec<Vec<T>> = self.groups().get_t(group as usize).unwrap_or_default();
+        phrases
+            .into_iter()
+            .filter(|i| i.as_slice() != std::slice::from_ref(term))
+            .collect()
+    }
+}
+
+impl<B, T> SynonymMap<B, T>
+where
+    B: GrowableBackend,
+    T: hashing::Hash + Eq + Deser + Serialize + DeserializeOwned + Clone,
+{
+    /// Adds a group of phrases that are synonyms of each other and returns its ID. Fails with
+    /// [`Error::UnsupportedOperation`] if a phrase is empty and with [`Error::DuplicateEntry`] if a
+    /// single term phrase is already part of another group.
+    pub fn insert_group(&mut self, phrases: &[Vec<T>]) -> Result<u32> {
+        if phrases.iter().any(|i| i.is_empty()) {
+            return Err(Error::UnsupportedOperation);
+        }
+        let terms: Vec<&T> = phrases
+            .iter()
+            .filter(|i| i.len() == 1)
+            .map(|i| &i[0])
+            .collect();
+
+        let id = self.len() as u32;
+        {
+            let mut map = self.map_mut();
+            if terms.iter().any(|i| map.get(i).is_some()) {
+                return Err(Error::DuplicateEntry);
+            }
+            map.grow_to(map.len() + terms.len())?;
+            for term in terms {
+                map.insert(term, &id)?;
+            }
+        }
+        self.groups_mut()
+            .extend(std::slice::from_ref(&phrases.to_vec()));
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bytestore::traits::creatable::MemCreatable;
+
+    fn phrase(phrase: &str) -> Vec<String> {
+        phrase.split(' ').map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn synonyms() {
+        let mut map: SynonymMap<_, String> = SynonymMap::create_mem_with_capacity(0).unwrap();
+        let kanji = phrase("kanji");
+        let group = [kanji.clone(), phrase("hanzi"), phrase("han")];
+        assert_eq!(map.insert_group(&group).unwrap(), 0);
+        assert_eq!(
+            map.insert_group(&[phrase("kana"), phrase("syllabary")])
+                .unwrap(),
+            1
+        );
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.synonyms(&kanji[0]), &group[1..]);
+        assert_eq!(map.synonyms(&"han".to_string()), &group[..2]);
+        assert_eq!(map.synonyms(&"kana".to_string()), [phrase("syllabary")]);
+        assert!(map.synonyms(&"chinese".to_string()).is_empty());
+
+        assert!(map.insert_group(&[phrase("kanji"), phrase("zi")]).is_err());
+        // Empty phrases are rejected without adding the group.
+        let res = map.insert_group(&[vec![], phrase("zi")]);
+        assert!(matches!(res, Err(Error::UnsupportedOperation)));
+        assert_eq!(map.len(), 2);
+        assert!(map.synonyms(&"zi".to_string()).is_empty());
+
+        // Multi-word phrases are returned as synonyms but can't be looked up themselves.
+        let group = [phrase("zi"), phrase("chinese character")];
+        assert_eq!(map.insert_group(&group).unwrap(), 2);
+        assert_eq!(map.synonyms(&"zi".to_string()), &group[1..]);
+        assert!(map.synonyms(&"chinese".to_string()).is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::index::meta::IndexMeta;
+use crate::index::synonym::SynonymMap;
 use crate::index::traits::helper::{DictImpl, PostingImpl, StorageImpl};
 use bytestore::backend::base::sub::GeneralSubBackend;
 
@@ -14,6 +16,12 @@ pub trait InvertedIndex<T, S>: InvertedIndexDict<T> {
     fn get_storage(&self) -> Self::StorageImpl<'_>;
 
     fn get_postings(&self) -> Self::PostingsImpl<'_>;
+
+    /// Returns the metadata of the index if it has any.
+    #[inline]
+    fn get_meta(&self) -> Option<IndexMeta<GeneralSubBackend<'_>>> {
+        None
+    }
 }
 
 pub trait InvertedIndexDict<T> {
@@ -23,4 +31,10 @@ pub trait InvertedIndexDict<T> {
         Self: 'a;
 
     fn get_dict(&self) -> Self::DictImpl<'_>;
+
+    /// Returns the synonyms of the terms in the index if it has any.
+    #[inline]
+    fn get_synonyms(&self) -> Option<SynonymMap<GeneralSubBackend<'_>, T>> {
+        None
+    }
 }
@@ -1,3 +1,4 @@
+pub mod analysis;
 pub mod edit;
 pub mod error;
 pub mod index;
@@ -1,15 +1,32 @@
-use crate::index::dict::IndexDictionary;
-use crate::index::posting::IndexPosting;
+use crate::analysis::{Analyzer, TermText};
+use crate::error::Error;
+use crate::index::dict::pattern::TermPattern;
+use crate::index::dict::{
+    FuzzyDictionary, IndexDictionary, PatternDictionary, PrefixDictionary, RangeDictionary,
+};
+use crate::index::meta::IndexMeta;
+use crate::index::posting::{count, CountablePosting, IndexPosting};
 use crate::index::traits::index::{InvertedIndex, InvertedIndexDict};
 use crate::retrieve::options::RetrieveOptions;
+use crate::retrieve::retriever::weighted::WeightedRetriever;
 use crate::retrieve::retriever::RetrieveAlgo;
+use crate::Result;
+use bytestore::backend::base::sub::GeneralSubBackend;
+use bytestore::components::map::hashing;
+use bytestore::traits::deser::Deser;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::marker::PhantomData;
+use std::ops::RangeBounds;
 
 pub struct RetrieverBuilder<'a, P, I> {
     index: &'a I,
     postings: P,
 
     options: RetrieveOptions,
+    meta: Option<IndexMeta<GeneralSubBackend<'a>>>,
+    /// Built from the index metadata on first use unless set explicitly.
+    analyzer: Option<Analyzer>,
 
     p: PhantomData<&'a ()>,
 }
@@ -21,10 +38,13 @@ impl<'a, P, I> RetrieverBuilder<'a, P, I> {
         I: InvertedIndex<T, S, PostingsImpl<'a> = P> + 'a,
     {
         let postings = index.get_postings();
+        let meta = index.get_meta();
         Self {
             index,
             postings,
             options: RetrieveOptions::default(),
+            meta,
+            analyzer: None,
             p: PhantomData,
         }
     }
@@ -33,6 +53,15 @@ impl<'a, P, I> RetrieverBuilder<'a, P, I> {
         self.options.term_ids.push(term);
     }
 
+    /// Adds a term with a weight. Weighted retrievers rank storage IDs by the highest weight of
+    /// the terms they contain.
+    pub fn add_weighted_term_id(&mut self, term: u64, weight: f32) {
+        let len = self.options.term_ids.len();
+        self.options.term_weights.resize(len, 1.0);
+        self.options.term_weights.push(weight);
+        self.options.term_ids.push(term);
+    }
+
     pub fn unique(mut self) -> Self {
         self.options.unique = true;
         self
@@ -40,11 +69,84 @@ impl<'a, P, I> RetrieverBuilder<'a, P, I> {
 
     pub fn with_term_ids(mut self, ids: &[u64]) -> Self {
         self.options.term_ids = ids.to_vec();
+        self.options.term_weights.clear();
+        self.options.phrases.clear();
         self
     }
 
     pub fn in_posting_lists(mut self, post_lists: &[u16]) -> Self {
         self.options.posting_lists = post_lists.to_vec();
+        self.options.posting_weights.clear();
+        self
+    }
+
+    /// Only retrieves storage IDs from the posting lists with the given names. Fails with
+    /// [`Error::UnknownPostingList`] if the index doesn't have one of the posting lists.
+    pub fn in_named_posting_lists(self, names: &[&str]) -> Result<Self> {
+        let names: Vec<_> = names.iter().map(|i| (*i, 1.0)).collect();
+        self.in_weighted_posting_lists(&names)
+    }
+
+    /// Only retrieves storage IDs from the posting lists with the given names, each with a weight.
+    /// Weighted retrievers multiply the weight of a term with the weight of the posting list it
+    /// was found in. Fails with [`Error::UnknownPostingList`] if the index doesn't have one of
+    /// the posting lists.
+    pub fn in_weighted_posting_lists(mut self, names: &[(&str, f32)]) -> Result<Self> {
+        let posting_names = self.posting_names();
+        let mut posting_lists = Vec::with_capacity(names.len());
+        for (name, _) in names {
+            let id = posting_names
+                .iter()
+                .position(|i| i == name)
+                .ok_or_else(|| Error::UnknownPostingList(name.to_string()))?;
+            posting_lists.push(id as u16);
+        }
+        self.options.posting_lists = posting_lists;
+        self.options.posting_weights = names.iter().map(|i| i.1).collect();
+        Ok(self)
+    }
+
+    /// Only retrieves storage IDs whose field `name` contains the terms. Fails with
+    /// [`Error::UnknownPostingList`] if the index has no such field.
+    #[inline]
+    pub fn in_field(self, name: &str) -> Result<Self> {
+        self.in_fields(&[name])
+    }
+
+    /// Only retrieves storage IDs where one of the fields `names` contains the terms. Fields are
+    /// named posting lists so this is the same as `in_named_posting_lists`.
+    #[inline]
+    pub fn in_fields(self, names: &[&str]) -> Result<Self> {
+        self.in_named_posting_lists(names)
+    }
+
+    /// Retrieves storage IDs from all named posting lists.
+    pub fn in_all_named_posting_lists(mut self) -> Self {
+        self.options.posting_lists = (0..self.posting_names().len() as u16).collect();
+        self.options.posting_weights.clear();
+        self
+    }
+
+    /// Returns the names of the posting lists of the index.
+    #[inline]
+    fn posting_names(&self) -> Vec<String> {
+        self.meta
+            .as_ref()
+            .map(|i| i.posting_lists())
+            .unwrap_or_default()
+    }
+
+    /// Sets the analyzer that turns texts added with `add_text` into terms. This has to be the
+    /// same analyzer the index has been built with. By default the analyzer configured in the
+    /// index metadata is used.
+    pub fn with_analyzer(mut self, analyzer: Analyzer) -> Self {
+        self.analyzer = Some(analyzer);
+        self
+    }
+
+    /// Sets the maximum amount of terms a single term expansion (eg. a prefix) adds to the query.
+    pub fn with_max_expansions(mut self, max_expansions: usize) -> Self {
+        self.options.max_expansions = max_expansions;
         self
     }
 }
@@ -64,14 +166,179 @@ impl<'a, P, I> RetrieverBuilder<'a, P, I> {
         Some(())
     }
 
+    /// Adds `term` and all of its synonyms that are in the index to the query. Synonyms consisting
+    /// of multiple terms get analyzed like texts added with `add_text` and only match storage IDs
+    /// that contain all of their terms. Use a retriever that returns storage IDs containing _any_
+    /// of the terms to retrieve `term` or any of its synonyms. Returns `None` if neither `term`
+    /// nor any of its synonyms is in the index.
     pub fn add_term<T>(&mut self, term: &T) -> Option<()>
     where
         I: InvertedIndexDict<T>,
+        T: hashing::Hash + Eq + Deser + Serialize + DeserializeOwned + TermText + TryFrom<String>,
+    {
+        let index: &'a I = self.index;
+        let synonyms = index
+            .get_synonyms()
+            .map(|i| i.synonyms(term))
+            .unwrap_or_default();
+
+        let mut added = false;
+        if let Some(tid) = index.get_dict().term_id(term) {
+            self.add_term_id(tid as u64);
+            added = true;
+        }
+        for phrase in synonyms {
+            added |= self.add_synonym(&phrase);
+        }
+        added.then_some(())
+    }
+
+    /// Adds a synonym phrase to the query. Returns `false` if the phrase can't match anything
+    /// because one of its terms isn't in the index.
+    fn add_synonym<T>(&mut self, phrase: &[T]) -> bool
+    where
+        I: InvertedIndexDict<T>,
+        T: TermText + TryFrom<String>,
+    {
+        let index: &'a I = self.index;
+        if let [term] = phrase {
+            let Some(tid) = index.get_dict().term_id(term) else {
+                return false;
+            };
+            self.add_term_id(tid as u64);
+            return true;
+        }
+
+        let text: Vec<String> = phrase.iter().map(|i| i.text()).collect();
+        let terms = self.analyze(&text.join(" "));
+
+        let dict = index.get_dict();
+        let mut term_ids = Vec::with_capacity(terms.len());
+        for term in terms {
+            let Some(tid) = T::try_from(term).ok().and_then(|i| dict.term_id(&i)) else {
+                return false;
+            };
+            term_ids.push(tid as u64);
+        }
+        if term_ids.is_empty() {
+            return false;
+        }
+        self.options.phrases.push(term_ids);
+        true
+    }
+
+    /// Turns `text` into terms using the builders analyzer.
+    fn analyze(&mut self, text: &str) -> Vec<String> {
+        let meta = &self.meta;
+        let analyzer = self.analyzer.get_or_insert_with(|| {
+            meta.as_ref()
+                .and_then(|i| i.analyzer_config())
+                .map(|i| i.build())
+                .unwrap_or_default()
+        });
+        analyzer.analyze(text)
+    }
+
+    /// Turns `text` into terms using the builders analyzer and adds all of them that are in the
+    /// index. Returns the amount of added terms.
+    pub fn add_text<T>(&mut self, text: &str) -> usize
+    where
+        I: InvertedIndexDict<T>,
+        T: From<String>,
     {
+        let terms = self.analyze(text);
+
         let dict = self.index.get_dict();
-        let tid = dict.term_id(term)? as u64;
-        self.add_term_id(tid);
-        Some(())
+        let mut added = 0;
+        for term in terms {
+            if let Some(term_id) = dict.term_id(&T::from(term)) {
+                self.add_term_id(term_id as u64);
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Adds all terms starting with `prefix` to the query. At most `max_expansions` terms are
+    /// added. Use a retriever that returns storage IDs containing _any_ of the terms to retrieve
+    /// the union of their postings. Returns the amount of added terms.
+    pub fn add_prefix(&mut self, prefix: &str) -> usize
+    where
+        I: InvertedIndexDict<String> + 'a,
+        I::DictImpl<'a>: PrefixDictionary,
+    {
+        let index: &'a I = self.index;
+        let term_ids = index
+            .get_dict()
+            .prefix_term_ids(prefix, self.options.max_expansions);
+        for term_id in term_ids.iter() {
+            self.add_term_id(*term_id as u64);
+        }
+        term_ids.len()
+    }
+
+    /// Adds all terms within `range` to the query. Terms are compared in the order of the
+    /// dictionary, so numbers stored as strings need to be padded to the same length. At most
+    /// `max_expansions` terms are added. Use a retriever that returns storage IDs containing _any_
+    /// of the terms to retrieve the union of their postings. Returns the amount of added terms.
+    pub fn add_range<T, R>(&mut self, range: R) -> usize
+    where
+        T: 'a,
+        R: RangeBounds<T>,
+        I: InvertedIndexDict<T> + 'a,
+        I::DictImpl<'a>: RangeDictionary<T>,
+    {
+        let index: &'a I = self.index;
+        let term_ids = index.get_dict().range_term_ids(
+            range.start_bound(),
+            range.end_bound(),
+            self.options.max_expansions,
+        );
+        for term_id in term_ids.iter() {
+            self.add_term_id(*term_id as u64);
+        }
+        term_ids.len()
+    }
+
+    /// Adds all terms matching the wildcard or regex `pattern` to the query. At most
+    /// `max_expansions` terms are added. Use a retriever that returns storage IDs containing _any_
+    /// of the terms to retrieve the union of their postings. Returns the amount of added terms.
+    pub fn add_pattern(&mut self, pattern: &TermPattern) -> usize
+    where
+        I: InvertedIndexDict<String> + 'a,
+        I::DictImpl<'a>: PatternDictionary,
+    {
+        let index: &'a I = self.index;
+        let term_ids = index
+            .get_dict()
+            .pattern_term_ids(pattern, self.options.max_expansions);
+        for term_id in term_ids.iter() {
+            self.add_term_id(*term_id as u64);
+        }
+        term_ids.len()
+    }
+
+    /// Adds all terms within the Levenshtein distance `max_distance` of `term` to the query. The
+    /// first `prefix_len` characters of the terms have to match exactly. Each expansion is weighted
+    /// with `1 / (1 + distance)` so weighted retrievers rank closer terms higher. At most
+    /// `max_expansions` of the closest terms are added. Returns the amount of added terms.
+    pub fn add_fuzzy(&mut self, term: &str, max_distance: u32, prefix_len: usize) -> usize
+    where
+        I: InvertedIndexDict<String> + 'a,
+        I::DictImpl<'a>: FuzzyDictionary,
+    {
+        let index: &'a I = self.index;
+        let expansions = index.get_dict().fuzzy_term_ids(
+            term,
+            max_distance,
+            prefix_len,
+            self.options.max_expansions,
+        );
+        for (term_id, distance) in expansions.iter() {
+            let weight = 1.0 / (1.0 + *distance as f32);
+            self.add_weighted_term_id(*term_id as u64, weight);
+        }
+        expansions.len()
     }
 
     pub fn in_all_postings(&mut self)
@@ -84,6 +351,7 @@ impl<'a, P, I> RetrieverBuilder<'a, P, I> {
             .try_into()
             .expect("Too many posting lists!");
         self.options.posting_lists = (0..len).collect();
+        self.options.posting_weights.clear();
     }
 }
 
@@ -98,4 +366,433 @@ where
     {
         A::new(&self.postings, self.options.clone())
     }
+
+    /// Returns a retriever yielding all storage IDs that contain at least _one_ of the terms,
+    /// together with the highest weight of their terms. Results are ordered by their weight.
+    #[inline]
+    pub fn weighted_retriever(&'a self) -> WeightedRetriever
+    where
+        P: IndexPosting,
+    {
+        WeightedRetriever::new(&self.postings, &self.options)
+    }
+}
+
+impl<'a, P, I> RetrieverBuilder<'a, P, I>
+where
+    P: CountablePosting,
+{
+    /// Returns the amount of unique storage IDs that contain at least _one_ of the terms or
+    /// phrases without retrieving them.
+    #[inline]
+    pub fn count_union(&self) -> usize {
+        let options = &self.options;
+        if options.phrases.is_empty() {
+            return self
+                .postings
+                .count_union(&options.posting_lists, &options.term_ids);
+        }
+
+        let mut ids = count::union_ids(&self.postings, &options.posting_lists, &options.term_ids);
+        for phrase in options.phrases.iter() {
+            ids.extend(count::intersection_ids(
+                &self.postings,
+                &options.posting_lists,
+                phrase,
+            ));
+        }
+        ids.len() as usize
+    }
+
+    /// Returns the amount of unique storage IDs that contain _all_ of the terms without retrieving
+    /// them.
+    #[inline]
+    pub fn count_intersection(&self) -> usize {
+        self.postings
+            .count_intersection(&self.options.posting_lists, &self.options.all_term_ids())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::analysis::config::AnalyzerConfig;
+    use crate::analysis::filter::{LowercaseFilter, StopwordFilter};
+    use crate::analysis::language::Language;
+    use crate::analysis::tokenizer::WordTokenizer;
+    use crate::edit::NewItem;
+    use crate::index::preset::{DefaultIndex, SortedIndex};
+    use crate::index::storage::IndexStorage;
+    use crate::index::test::{index_test_data, make_index};
+    use crate::retrieve::retriever::default::DefaultRetriever;
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::Creatable;
+    use std::ops::Bound;
+
+    #[test]
+    fn test_prefix() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: SortedIndex<_, String, String> = SortedIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+
+        let mut editor = index.editor();
+        for (terms, storage_item) in data.iter() {
+            editor
+                .insert(NewItem::new(terms.clone(), storage_item.clone()))
+                .unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let mut exp_terms: Vec<_> = exp_index.keys().filter(|i| i.starts_with("to")).collect();
+        exp_terms.sort_unstable();
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).unique();
+        assert_eq!(retrieve_builder.add_prefix("to"), exp_terms.len());
+
+        let mut exp: Vec<_> = exp_terms
+            .iter()
+            .flat_map(|term| exp_index[*term].iter().cloned())
+            .collect();
+        exp.sort_unstable();
+        exp.dedup();
+
+        let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+        let mut res: Vec<_> = retriever
+            .map(|i| index.storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        assert_eq!(res, exp);
+
+        // Expansions are capped.
+        let mut retrieve_builder = RetrieverBuilder::new(&index).with_max_expansions(1);
+        assert_eq!(retrieve_builder.add_prefix("to"), 1);
+        assert_eq!(retrieve_builder.add_prefix("xyz"), 0);
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: SortedIndex<_, String, String> = SortedIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+
+        let mut editor = index.editor();
+        for (terms, storage_item) in data.iter() {
+            editor
+                .insert(NewItem::new(terms.clone(), storage_item.clone()))
+                .unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index);
+        assert!(retrieve_builder.add_fuzzy("kanij", 2, 2) > 0);
+
+        let res: Vec<_> = retrieve_builder
+            .weighted_retriever()
+            .map(|(id, weight)| (index.storage().get_item(id as usize).unwrap(), weight))
+            .collect();
+
+        // All sentences containing "kanji" are found.
+        for exp in exp_index["kanji"].iter() {
+            assert!(res.iter().any(|i| i.0 == *exp));
+        }
+
+        // Results are ordered by their weight.
+        assert!(res.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        // An exact match ranks above all fuzzy matches.
+        let mut retrieve_builder = RetrieverBuilder::new(&index);
+        retrieve_builder.add_fuzzy("kanji", 2, 2);
+        let (first, weight) = retrieve_builder.weighted_retriever().next().unwrap();
+        assert_eq!(weight, 1.0);
+        assert!(exp_index["kanji"].contains(&index.storage().get_item(first as usize).unwrap()));
+    }
+
+    #[test]
+    fn test_pattern() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: SortedIndex<_, String, String> = SortedIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+
+        let mut editor = index.editor();
+        for (terms, storage_item) in data.iter() {
+            editor
+                .insert(NewItem::new(terms.clone(), storage_item.clone()))
+                .unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let pattern = TermPattern::wildcard("ka*ji").unwrap();
+        let exp_terms: Vec<_> = exp_index.keys().filter(|i| pattern.matches(i)).collect();
+        assert!(!exp_terms.is_empty());
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).unique();
+        assert_eq!(retrieve_builder.add_pattern(&pattern), exp_terms.len());
+
+        let mut exp: Vec<_> = exp_terms
+            .iter()
+            .flat_map(|term| exp_index[*term].iter().cloned())
+            .collect();
+        exp.sort_unstable();
+        exp.dedup();
+
+        let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+        let mut res: Vec<_> = retriever
+            .map(|i| index.storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        assert_eq!(res, exp);
+
+        // Expansions are capped.
+        let pattern = TermPattern::regex("t.*").unwrap();
+        let mut retrieve_builder = RetrieverBuilder::new(&index).with_max_expansions(2);
+        assert_eq!(retrieve_builder.add_pattern(&pattern), 2);
+    }
+
+    #[test]
+    fn test_range() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: SortedIndex<_, String, String> = SortedIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+
+        let mut editor = index.editor();
+        for (terms, storage_item) in data.iter() {
+            editor
+                .insert(NewItem::new(terms.clone(), storage_item.clone()))
+                .unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let (start, end) = ("k".to_string(), "radical".to_string());
+        let exp_terms: Vec<_> = exp_index
+            .keys()
+            .filter(|i| **i > start && **i <= end)
+            .collect();
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).unique();
+        let range = (Bound::Excluded(start), Bound::Included(end));
+        assert_eq!(retrieve_builder.add_range(range), exp_terms.len());
+
+        let mut exp: Vec<_> = exp_terms
+            .iter()
+            .flat_map(|term| exp_index[*term].iter().cloned())
+            .collect();
+        exp.sort_unstable();
+        exp.dedup();
+
+        let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+        let mut res: Vec<_> = retriever
+            .map(|i| index.storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_text() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DefaultIndex<_, String, String> = DefaultIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        let analyzer = Analyzer::new(WordTokenizer::new().with_allowed(&['\'', '-']))
+            .with_filter(LowercaseFilter)
+            .with_filter(StopwordFilter::new(["a", "the"]));
+
+        let mut editor = index.editor().with_analyzer(analyzer.clone());
+        for (_, sentence) in data.iter() {
+            editor.insert_text(sentence, sentence.clone()).unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        assert!(!index.dict().has_term(&"the".to_string()));
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index)
+            .with_analyzer(analyzer)
+            .unique();
+        assert_eq!(retrieve_builder.add_text::<String>("The Kanji!"), 1);
+
+        let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+        let mut res: Vec<_> = retriever
+            .map(|i| index.storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+
+        let mut exp = exp_index["kanji"].clone();
+        exp.sort_unstable();
+        exp.dedup();
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_analyzer_config() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DefaultIndex<_, String, String> = DefaultIndex::create(backend).unwrap();
+        let config = AnalyzerConfig::for_language(Language::English);
+        index.set_analyzer_config(&config).unwrap();
+        assert_eq!(index.meta().unwrap().analyzer_config(), Some(config));
+
+        // The editor and the retriever both pick up the analyzer from the metadata.
+        let mut editor = index.editor();
+        for (_, sentence) in index_test_data().0.iter() {
+            editor.insert_text(sentence, sentence.clone()).unwrap();
+        }
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        assert!(!index.dict().has_term(&"the".to_string()));
+        assert!(!index.dict().has_term(&"resources".to_string()));
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index);
+        assert_eq!(retrieve_builder.add_text::<String>("The Resource"), 1);
+        assert_eq!(retrieve_builder.count_union(), 2);
+    }
+
+    #[test]
+    fn test_synonyms() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let (data, exp_index) = index_test_data();
+        let mut index = make_index(backend, &data, false);
+        let group = [
+            vec!["kanji".to_string()],
+            vec!["radicals".to_string()],
+            vec!["Free".to_string(), "Resources".to_string()],
+        ];
+        index.synonyms_mut().unwrap().insert_group(&group).unwrap();
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).unique();
+        retrieve_builder.in_all_postings();
+        retrieve_builder.add_term(&"kanji".to_string()).unwrap();
+        assert!(retrieve_builder.add_term(&"hanzi".to_string()).is_none());
+
+        let retriever: DefaultRetriever<_> = retrieve_builder.retriever();
+        let mut res: Vec<_> = retriever
+            .map(|i| index.storage().get_item(i as usize).unwrap())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+
+        // The multi-word synonym only matches items containing both of its terms.
+        let mut exp = exp_index["kanji"].clone();
+        exp.extend(exp_index["radicals"].iter().cloned());
+        exp.extend(
+            exp_index["free"]
+                .iter()
+                .filter(|i| exp_index["resources"].contains(i))
+                .cloned(),
+        );
+        exp.sort_unstable();
+        exp.dedup();
+        assert!(!exp.contains(&exp_index["resources"][1]));
+        assert_eq!(res, exp);
+        assert_eq!(retrieve_builder.count_union(), exp.len());
+    }
+
+    #[test]
+    fn test_fields() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DefaultIndex<_, String, u32> = DefaultIndex::create(backend).unwrap();
+        index
+            .set_posting_lists(&["reading", "kanji", "gloss"])
+            .unwrap();
+        assert!(index.set_posting_lists(&["gloss"]).is_err());
+
+        let documents = [
+            ("かんじ", "漢字", "kanji chinese character"),
+            ("かな", "仮名", "kana japanese syllabary"),
+            ("かんじ", "感じ", "feeling sense impression"),
+        ];
+        let mut editor = index.editor();
+        for (i, (reading, kanji, gloss)) in documents.iter().enumerate() {
+            let fields = [("reading", *reading), ("kanji", *kanji), ("gloss", *gloss)];
+            editor.insert_text_document(&fields, i as u32).unwrap();
+        }
+        assert!(editor
+            .insert_text_document(&[("romaji", "kanji")], 3)
+            .is_err());
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let count = |fields: &[&str], term: &str| {
+            let mut retrieve_builder = RetrieverBuilder::new(&index).in_fields(fields).unwrap();
+            retrieve_builder.add_term(&term.to_string());
+            retrieve_builder.count_union()
+        };
+        assert_eq!(count(&["reading"], "かんじ"), 2);
+        assert_eq!(count(&["reading"], "kanji"), 0);
+        assert_eq!(count(&["kanji", "gloss"], "kanji"), 1);
+        assert!(RetrieverBuilder::new(&index).in_field("romaji").is_err());
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).in_all_named_posting_lists();
+        retrieve_builder.add_term(&"仮名".to_string());
+        assert_eq!(retrieve_builder.count_union(), 1);
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index)
+            .in_weighted_posting_lists(&[("reading", 1.0), ("gloss", 0.5)])
+            .unwrap();
+        retrieve_builder.add_term(&"kanji".to_string());
+        retrieve_builder.add_term(&"かな".to_string());
+        let res: Vec<_> = retrieve_builder.weighted_retriever().collect();
+        assert_eq!(res, [(1, 1.0), (0, 0.5)]);
+    }
+
+    #[test]
+    fn test_named_posting_lists() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: DefaultIndex<_, String, u32> = DefaultIndex::create(backend).unwrap();
+        index.set_posting_lists(&["english", "german"]).unwrap();
+        assert!(index.set_posting_lists(&["german", "english"]).is_err());
+        assert!(index
+            .set_posting_lists(&["english", "german", "german"])
+            .is_err());
+        assert_eq!(index.add_posting_list("german").unwrap(), 1);
+        assert_eq!(index.add_posting_list("french").unwrap(), 2);
+        assert_eq!(index.posting_lists(), ["english", "german", "french"]);
+
+        let texts = [
+            ("english", "the house is red"),
+            ("german", "das haus ist rot"),
+            ("french", "la maison est rouge"),
+            ("german", "ein rotes auto"),
+        ];
+        let mut editor = index.editor();
+        for (i, (lang, text)) in texts.iter().enumerate() {
+            editor
+                .insert_text_in_named_postings(text, i as u32, &[*lang])
+                .unwrap();
+        }
+        assert!(editor
+            .insert_text_in_named_postings("casa roja", 4, &["spanish"])
+            .is_err());
+        assert!(editor
+            .insert_in_named_postings(NewItem::new(vec!["x".to_string()], 5), &["german", "dutch"])
+            .is_err());
+        editor.commit().unwrap();
+        editor.finish().unwrap();
+
+        let count = |names: &[&str], term: &str| {
+            let mut retrieve_builder = RetrieverBuilder::new(&index)
+                .in_named_posting_lists(names)
+                .unwrap();
+            retrieve_builder.add_term(&term.to_string());
+            retrieve_builder.count_union()
+        };
+        assert_eq!(count(&["german"], "rot"), 1);
+        assert_eq!(count(&["english"], "rot"), 0);
+        assert_eq!(count(&["english", "french"], "red"), 1);
+        assert!(matches!(
+            RetrieverBuilder::new(&index).in_named_posting_lists(&["spanish"]),
+            Err(Error::UnknownPostingList(name)) if name == "spanish"
+        ));
+
+        let mut retrieve_builder = RetrieverBuilder::new(&index).in_all_named_posting_lists();
+        retrieve_builder.add_term(&"maison".to_string());
+        assert_eq!(retrieve_builder.count_union(), 1);
+    }
 }
@@ -1,15 +1,49 @@
+/// Default maximum amount of terms a single term expansion (eg. prefix) can add to a query.
+pub const DEFAULT_MAX_EXPANSIONS: usize = 1000;
+
 #[derive(Clone)]
 pub struct RetrieveOptions {
     // Input data for retrieving
     pub(crate) posting_lists: Vec<u16>,
     pub(crate) term_ids: Vec<u64>,
+    /// Weights of the terms at the same position in `term_ids`. Terms without weight have a
+    /// weight of 1.
+    pub(crate) term_weights: Vec<f32>,
+    /// Groups of terms that only match storage IDs containing _all_ of them, eg. multi-word
+    /// synonyms. Retrievers returning the union of the terms also return the storage IDs matching
+    /// any of the phrases. Retrievers returning the intersection treat their terms like all other
+    /// terms.
+    pub(crate) phrases: Vec<Vec<u64>>,
+    /// Weights of the posting lists at the same position in `posting_lists`. Posting lists
+    /// without weight have a weight of 1.
+    pub(crate) posting_weights: Vec<f32>,
 
     // Options
     pub(crate) unique: bool,
     pub(crate) limit: usize,
+    pub(crate) max_expansions: usize,
 }
 
-impl RetrieveOptions {}
+impl RetrieveOptions {
+    /// Returns the weight of the term at position `pos` in `term_ids`.
+    #[inline]
+    pub(crate) fn term_weight(&self, pos: usize) -> f32 {
+        self.term_weights.get(pos).copied().unwrap_or(1.0)
+    }
+
+    /// Returns the IDs of all terms including the terms of all phrases.
+    #[inline]
+    pub(crate) fn all_term_ids(&self) -> Vec<u64> {
+        let phrase_terms = self.phrases.iter().flatten();
+        self.term_ids.iter().chain(phrase_terms).copied().collect()
+    }
+
+    /// Returns the weight of the posting list at position `pos` in `posting_lists`.
+    #[inline]
+    pub(crate) fn posting_weight(&self, pos: usize) -> f32 {
+        self.posting_weights.get(pos).copied().unwrap_or(1.0)
+    }
+}
 
 impl Default for RetrieveOptions {
     #[inline]
@@ -17,8 +51,12 @@ impl Default for RetrieveOptions {
         Self {
             posting_lists: vec![0],
             term_ids: vec![],
+            term_weights: vec![],
+            phrases: vec![],
+            posting_weights: vec![],
             unique: false,
             limit: 0,
+            max_expansions: DEFAULT_MAX_EXPANSIONS,
         }
     }
 }
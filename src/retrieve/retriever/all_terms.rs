@@ -22,7 +22,7 @@ where
     #[inline]
     fn new(postings: &'a P, options: RetrieveOptions) -> Self {
         let mut iters = vec![];
-        for term_id in options.term_ids.iter().copied() {
+        for term_id in options.all_term_ids() {
             let mut term_iter = vec![];
             for posting_id in options.posting_lists.iter().copied() {
                 let Some(iter) = postings.posting_retriever(posting_id as usize, term_id) else {
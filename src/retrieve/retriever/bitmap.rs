@@ -0,0 +1,102 @@
+use crate::index::posting::bitmap::{intersection_bitmap, union_bitmap};
+use crate::index::posting::BitmapPosting;
+use crate::retrieve::options::RetrieveOptions;
+use crate::retrieve::retriever::RetrieveAlgo;
+use roaring::treemap::IntoIter;
+use std::marker::PhantomData;
+
+/// A retriever that returns all storage IDs which contained at least _one_ of the terms by using
+/// bitmap operations. The returned IDs are sorted and unique.
+pub struct BitmapUnionRetriever<'a, P> {
+    iter: IntoIter,
+    p: PhantomData<&'a P>,
+}
+
+impl<'a, P> RetrieveAlgo<'a, P> for BitmapUnionRetriever<'a, P>
+where
+    P: BitmapPosting + 'a,
+{
+    #[inline]
+    fn new(postings: &'a P, options: RetrieveOptions) -> Self {
+        let mut bitmap = union_bitmap(postings, &options.posting_lists, &options.term_ids);
+        for phrase in options.phrases.iter() {
+            bitmap |= intersection_bitmap(postings, &options.posting_lists, phrase);
+        }
+        Self {
+            iter: bitmap.into_iter(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<'a, P> Iterator for BitmapUnionRetriever<'a, P> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// A retriever that returns all storage IDs which contained _all_ of the terms by using bitmap
+/// operations. The returned IDs are sorted and unique.
+pub struct BitmapIntersectRetriever<'a, P> {
+    iter: IntoIter,
+    p: PhantomData<&'a P>,
+}
+
+impl<'a, P> RetrieveAlgo<'a, P> for BitmapIntersectRetriever<'a, P>
+where
+    P: BitmapPosting + 'a,
+{
+    #[inline]
+    fn new(postings: &'a P, options: RetrieveOptions) -> Self {
+        let term_ids = options.all_term_ids();
+        let iter = intersection_bitmap(postings, &options.posting_lists, &term_ids).into_iter();
+        Self {
+            iter,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<'a, P> Iterator for BitmapIntersectRetriever<'a, P> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::preset::RoaringIndex;
+    use crate::index::test::{
+        expected, fill_index, index_test_data, query_builder, retrieved_items, TEST_QUERIES,
+    };
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::Creatable;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_bitmap_retrievers() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: RoaringIndex<_, String, String> = RoaringIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        fill_index(index.editor(), &data);
+
+        for query in TEST_QUERIES {
+            let retrieve_builder = query_builder(&index, query);
+
+            let retriever: BitmapUnionRetriever<_> = retrieve_builder.retriever();
+            let res = retrieved_items(&index, retriever);
+            assert_eq!(res, expected(&exp_index, query, false));
+
+            let retriever: BitmapIntersectRetriever<_> = retrieve_builder.retriever();
+            let res = retrieved_items(&index, retriever);
+            assert_eq!(res, expected(&exp_index, query, true));
+        }
+    }
+}
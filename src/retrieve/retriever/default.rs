@@ -1,4 +1,4 @@
-use crate::index::posting::IndexPosting;
+use crate::index::posting::{count, IndexPosting};
 use crate::retrieve::options::RetrieveOptions;
 use crate::retrieve::retriever::RetrieveAlgo;
 use std::collections::HashSet;
@@ -18,6 +18,9 @@ where
 
     curr_posting: usize,
     seen: HashSet<u64>,
+
+    /// Storage IDs matching the phrases, returned after all terms.
+    phrase_ids: std::vec::IntoIter<u64>,
 }
 
 impl<'a, P> RetrieveAlgo<'a, P> for DefaultRetriever<'a, P>
@@ -26,12 +29,18 @@ where
 {
     #[inline]
     fn new(postings: &'a P, options: RetrieveOptions) -> Self {
+        let phrase_ids: Vec<u64> = options
+            .phrases
+            .iter()
+            .flat_map(|i| count::intersection_ids(postings, &options.posting_lists, i))
+            .collect();
         Self {
             postings,
             options,
             iter: None,
             curr_posting: 0,
             seen: HashSet::new(),
+            phrase_ids: phrase_ids.into_iter(),
         }
     }
 }
@@ -69,7 +78,9 @@ where
                 self.options.term_ids.pop();
             }
 
-            let curr_term = self.curr_term_id()?;
+            let Some(curr_term) = self.curr_term_id() else {
+                return self.phrase_ids.next();
+            };
 
             let iter = self
                 .postings
@@ -100,7 +111,7 @@ mod test {
         let index = sorted_test_index();
         let exp_index = index_test_data().1;
 
-        for (term, term_id) in index.dict().map.iter() {
+        for (term, term_id) in index.dict().iter() {
             let mut retrieve_builder = RetrieverBuilder::new(&index);
             retrieve_builder.add_term_id(term_id as u64);
             retrieve_builder.in_all_postings();
@@ -127,7 +138,7 @@ mod test {
         let dic_len = index.dict().len();
 
         for i in (1..(dic_len - 1)).step_by(13) {
-            for chunk in &index.dict().map.iter().chunks(i) {
+            for chunk in &index.dict().iter().chunks(i) {
                 let chunk = chunk.collect_vec();
 
                 let mut retrieve_builder = RetrieverBuilder::new(&index);
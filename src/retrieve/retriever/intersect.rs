@@ -0,0 +1,73 @@
+use crate::index::posting::seek::{next_intersection, term_cursors, Cursor};
+use crate::index::posting::{IndexPosting, SeekablePosting};
+use crate::retrieve::options::RetrieveOptions;
+use crate::retrieve::retriever::RetrieveAlgo;
+
+/// A retriever returning all storage IDs that contain _all_ terms. Uses [`SeekablePosting::seek`]
+/// to skip over storage IDs that can't match, so it only works with postings that are always
+/// sorted and seekable, like [`BlockPostings`].
+///
+/// [`BlockPostings`]: crate::index::posting::block::BlockPostings
+pub struct IntersectRetriever<'a, P>
+where
+    P: IndexPosting + 'a,
+{
+    /// Cursors of all terms. Each term has one cursor per posting list.
+    terms: Vec<Vec<Cursor<<P::PostingRetriever<'a> as IntoIterator>::IntoIter>>>,
+    target: Option<u64>,
+}
+
+impl<'a, P> RetrieveAlgo<'a, P> for IntersectRetriever<'a, P>
+where
+    P: IndexPosting + 'a,
+    <P::PostingRetriever<'a> as IntoIterator>::IntoIter: SeekablePosting,
+{
+    fn new(postings: &'a P, options: RetrieveOptions) -> Self {
+        let term_ids = options.all_term_ids();
+        let terms = term_cursors(postings, &options.posting_lists, &term_ids, |i| i);
+        let target = (!terms.is_empty()).then_some(0);
+        Self { terms, target }
+    }
+}
+
+impl<'a, P> Iterator for IntersectRetriever<'a, P>
+where
+    P: IndexPosting + 'a,
+    <P::PostingRetriever<'a> as IntoIterator>::IntoIter: SeekablePosting,
+{
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = next_intersection(&mut self.terms, self.target?);
+        self.target = next.and_then(|i| i.checked_add(1));
+        next
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::index::preset::BlockIndex;
+    use crate::index::test::{
+        expected, fill_index, index_test_data, query_builder, retrieved_items, TEST_QUERIES,
+    };
+    use bytestore::backend::memory::{MemoryBackend, MemoryData};
+    use bytestore::traits::creatable::Creatable;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_intersect() {
+        let backend = MemoryBackend::create(MemoryData::new(vec![0u8; 20])).unwrap();
+        let mut index: BlockIndex<_, String, String> = BlockIndex::create(backend).unwrap();
+        let (data, exp_index) = index_test_data();
+        fill_index(index.editor(), &data);
+
+        for query in TEST_QUERIES {
+            let retrieve_builder = query_builder(&index, query);
+            let retriever: IntersectRetriever<_> = retrieve_builder.retriever();
+            let res = retrieved_items(&index, retriever);
+            assert_eq!(res, expected(&exp_index, query, true));
+        }
+    }
+}
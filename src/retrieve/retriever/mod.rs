@@ -1,6 +1,9 @@
 pub mod all_terms;
+pub mod bitmap;
 pub mod default;
+pub mod intersect;
 pub mod ngram;
+pub mod weighted;
 mod sorted;
 
 use crate::retrieve::options::RetrieveOptions;
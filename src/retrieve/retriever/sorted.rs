@@ -249,7 +249,7 @@ mod test {
         let index = sorted_test_index();
         let exp_index = index_test_data().1;
 
-        for (term, term_id) in index.dict().map.iter() {
+        for (term, term_id) in index.dict().iter() {
             let mut retrieve_builder = RetrieverBuilder::new(&index);
             retrieve_builder.add_term_id(term_id as u64);
             retrieve_builder.in_all_postings();
@@ -273,7 +273,7 @@ mod test {
         let exp_index = index_test_data().1;
 
         for i in 1..=5 {
-            for chunk in &index.dict().map.iter().chunks(i) {
+            for chunk in &index.dict().iter().chunks(i) {
                 let chunk = chunk.collect_vec();
                 println!("{chunk:#?}");
 
@@ -0,0 +1,57 @@
+use crate::index::posting::{count, IndexPosting};
+use crate::retrieve::options::RetrieveOptions;
+use std::collections::HashMap;
+
+/// A retriever that returns all storage IDs which contained at least _one_ of the terms together
+/// with the highest weight of the terms they contained. The weight of a term in a posting list is
+/// the weight of the term multiplied with the weight of the posting list. The results are unique
+/// and ordered by their weight, starting with the highest one. Storage IDs matching a phrase in a
+/// posting list get the weight of the posting list.
+pub struct WeightedRetriever {
+    iter: std::vec::IntoIter<(u64, f32)>,
+}
+
+impl WeightedRetriever {
+    pub fn new<P: IndexPosting>(postings: &P, options: &RetrieveOptions) -> Self {
+        let mut weights: HashMap<u64, f32> = HashMap::new();
+
+        for (pos, term_id) in options.term_ids.iter().enumerate() {
+            for (post_pos, posting_id) in options.posting_lists.iter().enumerate() {
+                let Some(retriever) = postings.posting_retriever(*posting_id as usize, *term_id)
+                else {
+                    continue;
+                };
+                let weight = options.term_weight(pos) * options.posting_weight(post_pos);
+                for storage_id in retriever {
+                    let w = weights.entry(storage_id).or_insert(weight);
+                    *w = w.max(weight);
+                }
+            }
+        }
+
+        for phrase in options.phrases.iter() {
+            for (post_pos, posting_id) in options.posting_lists.iter().enumerate() {
+                let weight = options.posting_weight(post_pos);
+                for storage_id in count::intersection_ids(postings, &[*posting_id], phrase) {
+                    let w = weights.entry(storage_id).or_insert(weight);
+                    *w = w.max(weight);
+                }
+            }
+        }
+
+        let mut res: Vec<_> = weights.into_iter().collect();
+        res.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        Self {
+            iter: res.into_iter(),
+        }
+    }
+}
+
+impl Iterator for WeightedRetriever {
+    type Item = (u64, f32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}